@@ -0,0 +1,167 @@
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+// The configuration is looked for in this file (relative to the current directory), unless
+// the AOC_CONFIG environment variable gives another one
+const CONFIG_FILE: &str = "aoc.toml";
+
+// A puzzle parameter of a day (grid size, count of steps...), the examples of the puzzles
+// often use other values than the real inputs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub range: RangeInclusive<u64>,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: u64, range: RangeInclusive<u64>) -> Param {
+        Param {
+            name,
+            default,
+            range,
+        }
+    }
+}
+
+// Values of the parameters of a day, checked against their declarations
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    values: Vec<(&'static str, u64)>,
+}
+
+impl Params {
+    pub fn defaults(declared: &[Param]) -> Params {
+        Params {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.default))
+                .collect(),
+        }
+    }
+
+    // Days resolve their parameters once, in typed fields. Panics if the day does not declare
+    // this parameter, or if its declared range does not fit in T
+    pub fn get<T: TryFrom<u64>>(&self, name: &str) -> T {
+        let value = self
+            .values
+            .iter()
+            .find(|(param_name, _)| *param_name == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("Parameter `{name}` is not declared"));
+
+        T::try_from(value)
+            .unwrap_or_else(|_| panic!("Parameter `{name}` is out of range, found {value}"))
+    }
+
+    fn set(&mut self, declared: &[Param], name: &str, value: u64) -> Result<(), String> {
+        let Some(param) = declared.iter().find(|param| param.name == name) else {
+            let names: Vec<&str> = declared.iter().map(|param| param.name).collect();
+            return Err(match names.is_empty() {
+                true => format!("unknown parameter `{name}` (the day has no parameters)"),
+                false => format!("unknown parameter `{name}` ({})", names.join(", ")),
+            });
+        };
+        if !param.range.contains(&value) {
+            return Err(format!(
+                "parameter `{name}` must be between {} and {}, found {value}",
+                param.range.start(),
+                param.range.end()
+            ));
+        }
+
+        for (param_name, param_value) in &mut self.values {
+            if *param_name == name {
+                *param_value = value;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Parameters of the days, by sections named after them:
+//
+//   [day18]
+//   width = 7
+//   height = 7
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    sections: toml::Table,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let sections: toml::Table = text.parse().map_err(|err| format!("{err}"))?;
+        Ok(Config { sections })
+    }
+
+    // A missing file is only an error when it was asked for: without configuration, the
+    // parameters have their default values (the ones of the real inputs)
+    pub fn load(path: Option<&Path>) -> Result<Config, String> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => (default_config_path(), false),
+        };
+        if !required && !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let text = fs::read_to_string(&path)
+            .map_err(|err| format!("Can't read config file {}: {err}", path.display()))?;
+        Config::parse(&text).map_err(|err| format!("Invalid config file {}: {err}", path.display()))
+    }
+
+    // Override given as "day18.width=7"
+    pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+        let invalid = || format!("invalid parameter `{assignment}`, expected dayN.name=value");
+        let (key, value) = assignment.split_once('=').ok_or_else(invalid)?;
+        let (section, name) = key.trim().split_once('.').ok_or_else(invalid)?;
+        let value: i64 = value.trim().parse().map_err(|_| {
+            format!("invalid parameter `{assignment}`, the value must be an integer")
+        })?;
+
+        let section = self
+            .sections
+            .entry(section)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        let toml::Value::Table(section) = section else {
+            return Err(format!("`{key}` is not in a day section"));
+        };
+        section.insert(name.to_string(), toml::Value::Integer(value));
+
+        Ok(())
+    }
+
+    // Section [dayN] over the default values of the declared parameters
+    pub fn params(&self, day: u8, declared: &[Param]) -> Result<Params, String> {
+        let mut params = Params::defaults(declared);
+        let section_name = format!("day{day}");
+        let Some(section) = self.sections.get(&section_name) else {
+            return Ok(params);
+        };
+        let toml::Value::Table(section) = section else {
+            return Err(format!("[{section_name}] is not a section"));
+        };
+
+        for (name, value) in section {
+            let value = value
+                .as_integer()
+                .and_then(|value| u64::try_from(value).ok())
+                .ok_or_else(|| {
+                    format!("parameter `{name}` must be a positive integer, found {value}")
+                })?;
+            params.set(declared, name, value)?;
+        }
+
+        Ok(params)
+    }
+}
+
+pub fn default_config_path() -> PathBuf {
+    match env::var_os("AOC_CONFIG") {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(CONFIG_FILE),
+    }
+}
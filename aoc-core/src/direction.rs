@@ -0,0 +1,174 @@
+use crate::vector::{Scalar, Vector2d};
+
+// Headings on a grid, using the same convention everywhere: x grows to the right and y grows
+// downwards (like row indexes), so Up is (0,-1) and Down is (0,1)
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    // 4-neighbours, clockwise from Up
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    // 8-neighbours, clockwise from Up
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub fn from_char(dir_char: char) -> Option<Direction> {
+        match dir_char {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+
+    pub fn from_vector<T: Scalar + From<i8>>(v: &Vector2d<T>) -> Option<Direction> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.to_vector::<T>() == *v)
+    }
+
+    pub fn to_vector<T: From<i8>>(self) -> Vector2d<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        };
+
+        Vector2d {
+            x: T::from(x),
+            y: T::from(y),
+        }
+    }
+
+    // 90° clockwise
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    // 90° counterclockwise
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    // Rotate clockwise by steps of 45°
+    fn rotate(self, steps: usize) -> Direction {
+        Self::ALL[(self as usize + steps) % Self::ALL.len()]
+    }
+}
+
+impl<T: From<i8>> From<Direction> for Vector2d<T> {
+    fn from(direction: Direction) -> Self {
+        direction.to_vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectors_are_y_down() {
+        assert_eq!(Direction::Up.to_vector::<i64>(), Vector2d::new(0, -1));
+        assert_eq!(Direction::Right.to_vector::<i64>(), Vector2d::new(1, 0));
+        assert_eq!(Direction::Down.to_vector::<i64>(), Vector2d::new(0, 1));
+        assert_eq!(Direction::UpLeft.to_vector::<i64>(), Vector2d::new(-1, -1));
+
+        for direction in Direction::ALL {
+            let v: Vector2d<i64> = direction.into();
+            assert_eq!(Direction::from_vector(&v), Some(direction));
+        }
+        assert_eq!(Direction::from_vector(&Vector2d::new(2, 0)), None);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+
+        for direction in Direction::ALL {
+            let v: Vector2d<i64> = direction.into();
+            assert_eq!(direction.reverse().to_vector::<i64>(), -v);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+    }
+
+    #[test]
+    fn arrows() {
+        let directions: Vec<Option<Direction>> =
+            "^>v<x".chars().map(Direction::from_char).collect();
+        assert_eq!(
+            directions,
+            [
+                Some(Direction::Up),
+                Some(Direction::Right),
+                Some(Direction::Down),
+                Some(Direction::Left),
+                None
+            ]
+        );
+
+        for direction in Direction::CARDINALS {
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+        }
+        assert!(Direction::Left.is_horizontal() && !Direction::Left.is_vertical());
+        assert!(Direction::Down.is_vertical() && !Direction::Down.is_horizontal());
+    }
+}
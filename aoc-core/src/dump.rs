@@ -0,0 +1,26 @@
+use crate::config::Params;
+use crate::error::ParseError;
+use crate::solution::{Solution, Solver};
+use serde_json::Value;
+use std::io;
+
+// A day able to give its parsed puzzle as JSON, e.g. to inspect it or to feed other tools.
+// Days which build their model later (one per part) give the model of each part
+pub trait Dump: Solution {
+    fn model(input: &Self::Input) -> serde_json::Result<Value>;
+}
+
+// Object safe version of Dump, for the registry of the runner
+pub trait Dumper: Solver {
+    fn dump(&self, raw_data: &str, params: &Params) -> io::Result<Value>;
+}
+
+impl<D: Dump + Sync> Dumper for D {
+    fn dump(&self, raw_data: &str, params: &Params) -> io::Result<Value> {
+        let input = D::parse_with(raw_data, params).map_err(|err: ParseError| {
+            io::Error::new(io::ErrorKind::InvalidData, err.with_day(D::DAY))
+        })?;
+
+        Ok(D::model(&input)?)
+    }
+}
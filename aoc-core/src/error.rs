@@ -0,0 +1,72 @@
+use std::error::Error;
+use std::fmt;
+
+// Invalid puzzle input: where it happened, what was expected and what was found instead.
+// Lines and columns start at 1, the day is set once the error leaves the day parser
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    // Line and column indexes start at 0 (as given by enumerate)
+    pub fn new(
+        line_index: usize,
+        column_index: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            day: None,
+            line: line_index + 1,
+            column: column_index + 1,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    // Error located by a byte offset in the whole input
+    pub fn at_offset(
+        raw_data: &str,
+        offset: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> ParseError {
+        let before = &raw_data[..offset];
+        let line_index = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let column_index = before[line_start..].chars().count();
+
+        ParseError::new(line_index, column_index, expected, found)
+    }
+
+    // Error located just after the last line of the input (missing data)
+    pub fn at_end(raw_data: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(raw_data.lines().count(), 0, expected, "end of input")
+    }
+
+    pub fn with_day(mut self, day: u8) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {day}, ")?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
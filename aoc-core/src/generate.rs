@@ -0,0 +1,151 @@
+use crate::config::Params;
+use crate::grid::Grid;
+use crate::random::Rng;
+use crate::solution::{Solution, Solver};
+use crate::vector::Vector2d;
+use std::fmt;
+use std::str::FromStr;
+
+// What makes a random input harder depends on the day (value ranges, density of obstacles,
+// length of the lines...), the size of the input does not change
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn pick<T>(self, easy: T, normal: T, hard: T) -> T {
+        match self {
+            Difficulty::Easy => easy,
+            Difficulty::Normal => normal,
+            Difficulty::Hard => hard,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pick("easy", "normal", "hard"))
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Difficulty, String> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string() == value)
+            .ok_or_else(|| format!("unknown difficulty `{value}` (easy, normal or hard)"))
+    }
+}
+
+// A day able to build valid random inputs. The meaning of the size depends on the day
+// (lines, side of the grid, length of the disk map...), the default one is the size of
+// the real puzzle inputs
+pub trait Generate: Solution {
+    const DEFAULT_SIZE: usize;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String;
+
+    // Days with parameters fit their inputs to them (size of the area...), the others ignore
+    // them
+    fn generate_with(
+        rng: &mut Rng,
+        size: usize,
+        difficulty: Difficulty,
+        _params: &Params,
+    ) -> String {
+        Self::generate(rng, size, difficulty)
+    }
+}
+
+// Object safe version of Generate, for the registry of the runner
+pub trait Generator: Solver {
+    fn default_size(&self) -> usize;
+    fn random_input(
+        &self,
+        seed: u64,
+        size: usize,
+        difficulty: Difficulty,
+        params: &Params,
+    ) -> String;
+}
+
+impl<G: Generate + Sync> Generator for G {
+    fn default_size(&self) -> usize {
+        G::DEFAULT_SIZE
+    }
+
+    fn random_input(
+        &self,
+        seed: u64,
+        size: usize,
+        difficulty: Difficulty,
+        params: &Params,
+    ) -> String {
+        G::generate_with(&mut Rng::new(seed), size, difficulty, params)
+    }
+}
+
+// Random maze whose walls are true cells, surrounded by walls. Corridors are on odd positions
+// (the size is made odd), all of them are connected. Without loops there is a single path
+// between two positions, `loops` is the probability to open each remaining inner wall
+pub fn maze(rng: &mut Rng, width: usize, height: usize, loops: f64) -> Grid<bool> {
+    let width = (width.max(5) - 1) / 2 * 2 + 1;
+    let height = (height.max(5) - 1) / 2 * 2 + 1;
+    let mut walls = Grid::new(width, height, true);
+
+    // Depth first carving from the top left corridor
+    let start = Vector2d::new(1, 1);
+    walls.set(&start, false);
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        let next_positions: Vec<Vector2d> = [(2, 0), (-2, 0), (0, 2), (0, -2)]
+            .into_iter()
+            .map(|(x, y)| pos + Vector2d::new(x, y))
+            .filter(|next_pos| {
+                next_pos.x > 0
+                    && next_pos.y > 0
+                    && next_pos.x < width as i64 - 1
+                    && next_pos.y < height as i64 - 1
+                    && walls[*next_pos]
+            })
+            .collect();
+
+        if next_positions.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next_pos = *rng.pick(&next_positions);
+        walls.set(
+            &Vector2d::new((pos.x + next_pos.x) / 2, (pos.y + next_pos.y) / 2),
+            false,
+        );
+        walls.set(&next_pos, false);
+        stack.push(next_pos);
+    }
+
+    // Inner walls between two corridors
+    let inner_walls: Vec<Vector2d> = walls
+        .find_all(|wall| *wall)
+        .filter(|pos| {
+            let inside =
+                pos.x > 0 && pos.y > 0 && pos.x < width as i64 - 1 && pos.y < height as i64 - 1;
+            inside && (pos.x % 2 == 1) != (pos.y % 2 == 1)
+        })
+        .collect();
+    for pos in inner_walls {
+        if rng.chance(loops) {
+            walls.set(&pos, false);
+        }
+    }
+
+    walls
+}
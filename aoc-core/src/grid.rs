@@ -0,0 +1,391 @@
+use crate::direction::Direction;
+use crate::error::ParseError;
+use crate::vector::Vector2d;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops;
+
+// Rectangular grid stored row by row, positions use the y-down convention of Direction
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    // Fixed size rows, for grids written in the code (keypads...)
+    pub fn from_array<const W: usize, const H: usize>(rows: [[T; W]; H]) -> Grid<T> {
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width: W,
+            height: H,
+        }
+    }
+
+    // Rows must all have the same length, the error locates the first one which does not
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            check_row_length(y, row.len(), width)?;
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    // Build a grid from a char map (one row per line): the mapping rejects unknown characters
+    // (described by expected), and rows must all have the same length
+    pub fn try_parse<F>(raw_data: &str, mapping: F, expected: &str) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let (grid, _) = Self::try_parse_with_markers(raw_data, &[], mapping, expected)?;
+        Ok(grid)
+    }
+
+    // Same as try_parse, but also reports the positions of the given marker characters
+    // (start/exit tiles, robot...), each one found at most once; markers are still given to
+    // the mapping closure
+    pub fn try_parse_with_markers<F>(
+        raw_data: &str,
+        markers: &[char],
+        mut mapping: F,
+        expected: &str,
+    ) -> Result<(Grid<T>, HashMap<char, Vector2d>), ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut marker_positions = HashMap::new();
+        let mut rows: Vec<Vec<T>> = vec![];
+
+        for (y, line) in raw_data.lines().enumerate() {
+            let mut row = vec![];
+            for (x, c) in line.chars().enumerate() {
+                if markers.contains(&c)
+                    && marker_positions
+                        .insert(c, Vector2d::new(x as i64, y as i64))
+                        .is_some()
+                {
+                    return Err(ParseError::new(
+                        y,
+                        x,
+                        format!("a single `{c}`"),
+                        format!("another `{c}`"),
+                    ));
+                }
+
+                match mapping(c) {
+                    Some(cell) => row.push(cell),
+                    None => return Err(ParseError::new(y, x, expected, format!("`{c}`"))),
+                }
+            }
+
+            if let Some(first_row) = rows.first() {
+                check_row_length(y, row.len(), first_row.len())?;
+            }
+
+            rows.push(row);
+        }
+
+        Ok((Self::from_rows(rows)?, marker_positions))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> Vector2d {
+        Vector2d::new(self.width as i64, self.height as i64)
+    }
+
+    pub fn contains(&self, pos: &Vector2d) -> bool {
+        pos.x >= 0 && pos.x < self.width as i64 && pos.y >= 0 && pos.y < self.height as i64
+    }
+
+    fn offset(&self, pos: &Vector2d) -> Option<usize> {
+        match self.contains(pos) {
+            true => Some(pos.y as usize * self.width + pos.x as usize),
+            false => None,
+        }
+    }
+
+    pub fn get(&self, pos: &Vector2d) -> Option<&T> {
+        let offset = self.offset(pos)?;
+        self.cells.get(offset)
+    }
+
+    pub fn get_mut(&mut self, pos: &Vector2d) -> Option<&mut T> {
+        let offset = self.offset(pos)?;
+        self.cells.get_mut(offset)
+    }
+
+    // Returns false if the position is outside the grid
+    pub fn set(&mut self, pos: &Vector2d, value: T) -> bool {
+        match self.get_mut(pos) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Vector2d> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |index| Vector2d::new((index % width) as i64, (index / width) as i64))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2d, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vector2d, &mut T)> + '_ {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    // Neighbour positions inside the grid, for the given directions
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: &Vector2d,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Vector2d> + 'a {
+        let pos = *pos;
+        directions
+            .iter()
+            .map(move |direction| pos + *direction)
+            .filter(|next_pos| self.contains(next_pos))
+    }
+
+    pub fn neighbours4(&self, pos: &Vector2d) -> impl Iterator<Item = Vector2d> + '_ {
+        self.neighbours(pos, &Direction::CARDINALS)
+    }
+
+    pub fn neighbours8(&self, pos: &Vector2d) -> impl Iterator<Item = Vector2d> + '_ {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // chunks() does not accept a null size
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.height {
+            return None;
+        }
+
+        Some(&self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(if x < self.width { self.height } else { 0 })
+    }
+
+    // Cells from a start position (included) towards a direction (row, column or diagonal),
+    // until the border of the grid
+    pub fn ray(
+        &self,
+        start: &Vector2d,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Vector2d, &T)> + '_ {
+        let mut pos = *start;
+        std::iter::from_fn(move || {
+            let cell = self.get(&pos)?;
+            let current_pos = pos;
+            pos = pos + direction;
+            Some((current_pos, cell))
+        })
+    }
+
+    pub fn find<P>(&self, mut predicate: P) -> Option<Vector2d>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Vector2d> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, mut mapping: F) -> Grid<U>
+    where
+        F: FnMut(Vector2d, &T) -> U,
+    {
+        Grid {
+            cells: self.iter().map(|(pos, cell)| mapping(pos, cell)).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+fn check_row_length(y: usize, length: usize, width: usize) -> Result<(), ParseError> {
+    match length == width {
+        true => Ok(()),
+        false => Err(ParseError::new(
+            y,
+            length.min(width),
+            format!("a row of {width} cells"),
+            format!("{length} cells"),
+        )),
+    }
+}
+
+impl<T> ops::Index<Vector2d> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector2d) -> &T {
+        match self.get(&pos) {
+            Some(cell) => cell,
+            None => panic!("Position {pos} is outside the grid"),
+        }
+    }
+}
+
+impl<T> ops::IndexMut<Vector2d> for Grid<T> {
+    fn index_mut(&mut self, pos: Vector2d) -> &mut T {
+        match self.get_mut(&pos) {
+            Some(cell) => cell,
+            None => panic!("Position {pos} is outside the grid"),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::try_parse("123\n456\n", |c| c.to_digit(10), "a digit").unwrap()
+    }
+
+    #[test]
+    fn bounds() {
+        let mut grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.size(), Vector2d::new(3, 2));
+        assert_eq!(grid.get(&Vector2d::new(2, 1)), Some(&6));
+        assert_eq!(grid[Vector2d::new(0, 1)], 4);
+
+        for outside in [(-1, 0), (3, 0), (0, -1), (0, 2)] {
+            let pos = Vector2d::new(outside.0, outside.1);
+            assert!(!grid.contains(&pos));
+            assert_eq!(grid.get(&pos), None);
+            assert!(!grid.set(&pos, 0));
+        }
+        assert!(grid.set(&Vector2d::new(1, 0), 9));
+        assert_eq!(grid.row(0), Some(&[1, 9, 3][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), [&3, &6]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = digits();
+        let corner = Vector2d::new(0, 0);
+        let values = |positions: Vec<Vector2d>| -> Vec<u32> {
+            positions.iter().map(|pos| grid[*pos]).collect()
+        };
+
+        assert_eq!(values(grid.neighbours4(&corner).collect()), [2, 4]);
+        assert_eq!(values(grid.neighbours8(&corner).collect()), [2, 5, 4]);
+        assert_eq!(grid.neighbours8(&Vector2d::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn diagonal_rays() {
+        let grid = Grid::from_array([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let ray = |start: (i64, i64), direction| -> Vec<u32> {
+            grid.ray(&Vector2d::new(start.0, start.1), direction)
+                .map(|(_, value)| *value)
+                .collect()
+        };
+
+        assert_eq!(ray((0, 0), Direction::DownRight), [1, 5, 9]);
+        assert_eq!(ray((0, 2), Direction::UpRight), [7, 5, 3]);
+        assert_eq!(ray((1, 0), Direction::DownLeft), [2, 4]);
+        assert!(ray((3, 0), Direction::DownLeft).is_empty());
+    }
+
+    #[test]
+    fn markers_are_reported() {
+        let (grid, markers) =
+            Grid::try_parse_with_markers("#S.\n.#E\n", &['S', 'E'], |c| Some(c == '#'), "a tile")
+                .unwrap();
+        assert_eq!(markers[&'S'], Vector2d::new(1, 0));
+        assert_eq!(markers[&'E'], Vector2d::new(2, 1));
+        // Markers are cells too
+        assert_eq!(grid.find_all(|wall| !wall).count(), 4);
+    }
+
+    #[test]
+    fn repeated_markers_are_errors() {
+        let err = Grid::try_parse_with_markers("#S.\n.#S\n", &['S'], Some, "a tile").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "a single `S`");
+    }
+
+    #[test]
+    fn invalid_grids_are_errors() {
+        let err = Grid::try_parse("12\n3x\n", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 1, "a digit", "`x`"));
+
+        let err = Grid::try_parse("12\n3\n", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 1, "a row of 2 cells", "1 cells"));
+
+        let err = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]]).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, "a row of 2 cells", "3 cells"));
+    }
+}
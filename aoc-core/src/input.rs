@@ -0,0 +1,88 @@
+use crate::config::{Config, Params};
+use crate::solution::Solution;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+// Puzzle inputs are personal, they are looked for in this directory (relative to the
+// current one), unless the AOC_INPUTS environment variable gives another location
+const INPUTS_DIR: &str = "inputs";
+
+pub fn inputs_dir() -> PathBuf {
+    match env::var_os("AOC_INPUTS") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(INPUTS_DIR),
+    }
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    inputs_dir().join(format!("day{day:02}.txt"))
+}
+
+// Reads the given input file ("-" reads stdin), or the default input file of the day
+pub fn read_input(day: u8, path: Option<&Path>) -> io::Result<String> {
+    let path = match path {
+        Some(path) if path == Path::new("-") => {
+            let mut raw_data = String::new();
+            io::stdin().read_to_string(&mut raw_data)?;
+            return Ok(raw_data);
+        }
+        Some(path) => path.to_path_buf(),
+        None => default_input_path(day),
+    };
+
+    fs::read_to_string(&path).map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("Can't read input file {}: {err}", path.display()),
+        )
+    })
+}
+
+// Input of a day binary: the first argument which is not a parameter is an optional input path
+pub fn from_args(day: u8) -> String {
+    let path = env::args_os()
+        .skip(1)
+        .find(|arg| !arg.to_string_lossy().contains('='))
+        .map(PathBuf::from);
+
+    match read_input(day, path.as_deref()) {
+        Ok(raw_data) => raw_data,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+// Parameters of a day binary: the configuration file, then the "name=value" arguments
+pub fn params_from_args<S: Solution>() -> Result<Params, String> {
+    let mut config = Config::load(None)?;
+    for arg in env::args_os().skip(1) {
+        let arg = arg.to_string_lossy();
+        if arg.contains('=') {
+            config.set(&format!("day{}.{arg}", S::DAY))?;
+        }
+    }
+
+    config.params(S::DAY, S::PARAMS)
+}
+
+// Parsed input of a day binary, invalid inputs (or parameters) end the process with a diagnostic
+pub fn parse_or_exit<S: Solution>(raw_data: &str) -> S::Input {
+    let params = match params_from_args::<S>() {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("Day {}: {err}", S::DAY);
+            process::exit(1);
+        }
+    };
+
+    match S::parse_with(raw_data, &params) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err.with_day(S::DAY));
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+pub mod config;
+pub mod direction;
+#[cfg(feature = "serde")]
+pub mod dump;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parser;
+#[cfg(feature = "png")]
+pub mod png;
+pub mod random;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod terminal;
+pub mod vector;
+
+pub use config::{Config, Param, Params};
+pub use direction::Direction;
+#[cfg(feature = "serde")]
+pub use dump::{Dump, Dumper};
+pub use error::ParseError;
+pub use generate::{Difficulty, Generate, Generator};
+pub use grid::Grid;
+pub use parser::Parser;
+pub use random::Rng;
+pub use render::{Canvas, Palette, Rgb, Visualize, Visualizer};
+pub use solution::{Answer, PartRun, Run, Solution, Solver};
+pub use vector::{Scalar, Vector2d};
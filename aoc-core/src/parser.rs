@@ -0,0 +1,334 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::vector::Vector2d;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// Cursor over a part of the puzzle input (the whole input, a section, a line...).
+// Parts are always sub slices of the whole input, so errors are located in it
+#[derive(Clone, Copy, Debug)]
+pub struct Parser<'a> {
+    raw_data: &'a str,
+    start: usize,
+    end: usize,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl<'a> Parser<'a> {
+    pub fn new(raw_data: &'a str) -> Parser<'a> {
+        Parser {
+            raw_data,
+            start: 0,
+            end: raw_data.len(),
+        }
+    }
+
+    // Sub parser over a slice of the remaining text
+    fn part(&self, text: &'a str) -> Parser<'a> {
+        let start = text.as_ptr() as usize - self.raw_data.as_ptr() as usize;
+        Parser {
+            raw_data: self.raw_data,
+            start,
+            end: start + text.len(),
+        }
+    }
+
+    // Remaining text
+    pub fn rest(&self) -> &'a str {
+        &self.raw_data[self.start..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let text = &self.rest()[..len];
+        self.start += len;
+        text
+    }
+
+    // Error at the current position, the found part is the next token
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest();
+        let token: String = match rest.chars().next() {
+            None if self.end == self.raw_data.len() => "end of input".to_string(),
+            None => "end of line".to_string(),
+            Some(c) if c.is_whitespace() => format!("{c:?}"),
+            Some(c) if c.is_alphanumeric() => {
+                let token_end = rest.find(|c: char| !c.is_alphanumeric());
+                format!("`{}`", &rest[..token_end.unwrap_or(rest.len())])
+            }
+            Some(c) => format!("`{c}`"),
+        };
+
+        ParseError::at_offset(self.raw_data, self.start, expected, token)
+    }
+
+    // Error at the end of the text (missing data)
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        let mut end = *self;
+        end.start = end.end;
+        end.error(expected)
+    }
+
+    pub fn literal(&mut self, text: &str) -> ParseResult<()> {
+        match self.rest().starts_with(text) {
+            true => {
+                self.advance(text.len());
+                Ok(())
+            }
+            false => Err(self.error(format!("`{text}`"))),
+        }
+    }
+
+    pub fn take_while<P>(&mut self, mut predicate: P) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.advance(len)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    // Digits only
+    pub fn unsigned<T: FromStr>(&mut self) -> ParseResult<T> {
+        let saved = *self;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits.parse::<T>().map_err(|_| {
+            *self = saved;
+            self.error("an unsigned integer")
+        })
+    }
+
+    // Digits with an optional sign
+    pub fn signed<T: FromStr>(&mut self) -> ParseResult<T> {
+        let saved = *self;
+        let sign_len = match self.rest().starts_with(['-', '+']) {
+            true => 1,
+            false => 0,
+        };
+        let digits_len = self.rest()[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign_len);
+
+        let number = self.advance(sign_len + digits_len);
+        number.parse::<T>().map_err(|_| {
+            *self = saved;
+            self.error("an integer")
+        })
+    }
+
+    // At least one item, items are separated by the given text
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> ParseResult<Vec<T>>
+    where
+        F: FnMut(&mut Parser<'a>) -> ParseResult<T>,
+    {
+        let mut items = vec![item(self)?];
+        while self.rest().starts_with(separator) {
+            self.advance(separator.len());
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    // Value of a `key=value` pair
+    pub fn key_value<T, F>(&mut self, key: &str, value: F) -> ParseResult<T>
+    where
+        F: FnOnce(&mut Parser<'a>) -> ParseResult<T>,
+    {
+        self.literal(key)?;
+        self.literal("=")?;
+        value(self)
+    }
+
+    // Nothing must remain
+    pub fn end(&self) -> ParseResult<()> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.error("nothing more")),
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Parser<'a>> + '_ {
+        self.rest().lines().map(|line| self.part(line))
+    }
+
+    // Parse every line with the given closure, which must consume the whole line
+    pub fn parse_lines<T, F>(&self, mut line_parser: F) -> ParseResult<Vec<T>>
+    where
+        F: FnMut(&mut Parser<'a>) -> ParseResult<T>,
+    {
+        self.lines()
+            .map(|mut line| {
+                let value = line_parser(&mut line)?;
+                line.end()?;
+                Ok(value)
+            })
+            .collect()
+    }
+
+    // Parts of the text separated by blank lines
+    pub fn sections(&self) -> Vec<Parser<'a>> {
+        let mut sections = vec![];
+        let mut section: Option<(&'a str, &'a str)> = None; // First and last lines
+
+        for line in self.rest().lines() {
+            match (line.trim().is_empty(), section) {
+                (true, Some(lines)) => {
+                    sections.push(lines);
+                    section = None;
+                }
+                (true, None) => {}
+                (false, Some((first, _))) => section = Some((first, line)),
+                (false, None) => section = Some((line, line)),
+            }
+        }
+        sections.extend(section);
+
+        sections
+            .into_iter()
+            .map(|(first, last)| {
+                let start = self.part(first).start;
+                let end = self.part(last).end;
+                self.part(&self.raw_data[start..end])
+            })
+            .collect()
+    }
+
+    // Exactly N sections, described by expected when missing
+    pub fn sections_exact<const N: usize>(&self, expected: &str) -> ParseResult<[Parser<'a>; N]> {
+        let sections = self.sections();
+        if let Some(extra_section) = sections.get(N) {
+            return Err(extra_section.error("end of input"));
+        }
+
+        sections.try_into().map_err(|_| self.error_at_end(expected))
+    }
+
+    // Char grid: one row per line, the mapping rejects unknown characters
+    pub fn grid<T, F>(&self, mapping: F, expected: &str) -> ParseResult<Grid<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let (grid, _) = self.grid_with_markers(&[], mapping, expected)?;
+        Ok(grid)
+    }
+
+    pub fn grid_with_markers<T, F>(
+        &self,
+        markers: &[char],
+        mapping: F,
+        expected: &str,
+    ) -> ParseResult<(Grid<T>, HashMap<char, Vector2d>)>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        Grid::try_parse_with_markers(self.rest(), markers, mapping, expected).map_err(|mut err| {
+            // Grid errors are located in the remaining text
+            let origin = ParseError::at_offset(self.raw_data, self.start, "", "");
+            if err.line == 1 {
+                err.column += origin.column - 1;
+            }
+            err.line += origin.line - 1;
+            err
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_and_unsigned_numbers() {
+        let mut parser = Parser::new("12,-7,+3");
+        assert_eq!(parser.unsigned::<u32>(), Ok(12));
+        parser.literal(",").unwrap();
+        assert_eq!(parser.signed::<i32>(), Ok(-7));
+        parser.literal(",").unwrap();
+        assert_eq!(parser.signed::<i32>(), Ok(3));
+        assert_eq!(parser.end(), Ok(()));
+
+        let mut parser = Parser::new("-7");
+        assert!(parser.unsigned::<u32>().is_err());
+        // A failed number does not consume anything
+        assert_eq!(parser.rest(), "-7");
+    }
+
+    #[test]
+    fn key_values_and_separated_items() {
+        let mut parser = Parser::new("p=0,4 v=3,-3");
+        let position = parser
+            .key_value("p", |parser| parser.separated(",", Parser::signed::<i64>))
+            .unwrap();
+        parser.literal(" ").unwrap();
+        let velocity = parser
+            .key_value("v", |parser| parser.separated(",", Parser::signed::<i64>))
+            .unwrap();
+
+        assert_eq!(position, [0, 4]);
+        assert_eq!(velocity, [3, -3]);
+        assert!(parser.is_empty());
+    }
+
+    #[test]
+    fn sections_are_separated_by_blank_lines() {
+        let parser = Parser::new("a\nb\n\n\nc\n  \nd\n");
+        let sections: Vec<&str> = parser
+            .sections()
+            .iter()
+            .map(|section| section.rest())
+            .collect();
+        assert_eq!(sections, ["a\nb", "c", "d"]);
+
+        assert!(parser.sections_exact::<3>("3 sections").is_ok());
+        let err = parser.sections_exact::<2>("2 sections").unwrap_err();
+        assert_eq!((err.line, err.column), (7, 1));
+        let err = parser.sections_exact::<4>("4 sections").unwrap_err();
+        assert_eq!(err.expected, "4 sections");
+    }
+
+    #[test]
+    fn errors_are_located_in_the_whole_input() {
+        let parser = Parser::new("1 2\n3 x4\n");
+        let err = parser
+            .parse_lines(|line| line.separated(" ", Parser::unsigned::<u32>))
+            .unwrap_err();
+        assert_eq!(err, ParseError::new(1, 2, "an unsigned integer", "`x4`"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected an unsigned integer, found `x4`"
+        );
+
+        let err = Parser::new("1 2 \n")
+            .parse_lines(|line| line.separated(" ", Parser::unsigned::<u32>))
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.found, "end of line");
+    }
+
+    #[test]
+    fn grid_errors_are_shifted_to_the_section() {
+        let parser = Parser::new("header\n\n.#\n.?\n");
+        let [_, grid] = parser.sections_exact::<2>("a grid").unwrap();
+        let err = grid
+            .grid(
+                |c| match c {
+                    '.' => Some(false),
+                    '#' => Some(true),
+                    _ => None,
+                },
+                "a tile",
+            )
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (4, 2));
+        assert_eq!(err.found, "`?`");
+    }
+}
@@ -0,0 +1,80 @@
+use crate::grid::Grid;
+use crate::render::{Canvas, Rgb};
+use crate::vector::Vector2d;
+use image::{ImageFormat, RgbImage};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Every cell is drawn as a square of scale x scale pixels
+pub fn save_png(image: &Grid<Rgb>, scale: u32, path: &Path) -> io::Result<()> {
+    let scale = scale.max(1);
+    let width = image.width() as u32 * scale;
+    let height = image.height() as u32 * scale;
+
+    let pixels = RgbImage::from_fn(width, height, |x, y| {
+        let color = image[Vector2d::new((x / scale) as i64, (y / scale) as i64)];
+        image::Rgb([color.r, color.g, color.b])
+    });
+
+    pixels
+        .save_with_format(path, ImageFormat::Png)
+        .map_err(|err| io::Error::other(format!("Can't write {}: {err}", path.display())))
+}
+
+// Writes the still image as <name>.png and the frames as <name>_NNNNN.png in a directory,
+// only one frame out of `every` is kept for long simulations
+pub struct PngCanvas {
+    dir: PathBuf,
+    name: String,
+    scale: u32,
+    every: usize,
+    frame_count: usize,
+    written: Vec<PathBuf>,
+}
+
+impl PngCanvas {
+    pub fn new(dir: &Path, name: &str, scale: u32, every: usize) -> io::Result<PngCanvas> {
+        fs::create_dir_all(dir)?;
+
+        Ok(PngCanvas {
+            dir: dir.to_path_buf(),
+            name: name.to_string(),
+            scale,
+            every: every.max(1),
+            frame_count: 0,
+            written: vec![],
+        })
+    }
+
+    pub fn written(&self) -> &[PathBuf] {
+        &self.written
+    }
+
+    fn save(&mut self, image: &Grid<Rgb>, file_name: String) -> io::Result<()> {
+        let path = self.dir.join(file_name);
+        save_png(image, self.scale, &path)?;
+        self.written.push(path);
+
+        Ok(())
+    }
+}
+
+impl Canvas for PngCanvas {
+    fn still(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        self.save(image, format!("{}.png", self.name))
+    }
+
+    fn frame(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        let index = self.frame_count;
+        self.frame_count += 1;
+        if !index.is_multiple_of(self.every) {
+            return Ok(());
+        }
+
+        self.save(
+            image,
+            format!("{}_{:05}.png", self.name, index / self.every),
+        )
+    }
+}
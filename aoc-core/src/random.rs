@@ -0,0 +1,69 @@
+// Small seeded pseudo random generator (SplitMix64): the same seed always gives the same
+// values, on every platform and whatever the version of the dependencies
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    // Value in [0, n), n must not be null
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // Value in [low, high]
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "Empty range");
+        let span = high.abs_diff(low).wrapping_add(1);
+        match span {
+            0 => self.next_u64() as i64, // Whole i64 range
+            _ => low.wrapping_add((self.next_u64() % span) as i64),
+        }
+    }
+
+    // True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        unit < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn between_stays_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..100 {
+            assert!((-3..=5).contains(&rng.between(-3, 5)));
+        }
+        assert_eq!(rng.between(4, 4), 4);
+        // The whole i64 range must not overflow
+        rng.between(i64::MIN, i64::MAX);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::config::Params;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::solution::{Solution, Solver};
+use crate::vector::Vector2d;
+use std::io;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const GREY: Rgb = Rgb::new(96, 96, 96);
+    pub const RED: Rgb = Rgb::new(230, 40, 40);
+    pub const GREEN: Rgb = Rgb::new(0, 200, 0);
+    pub const BLUE: Rgb = Rgb::new(40, 90, 230);
+    pub const YELLOW: Rgb = Rgb::new(250, 210, 0);
+    pub const BROWN: Rgb = Rgb::new(150, 100, 40);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    // Linear interpolation, t is clamped to [0, 1]
+    pub fn mix(&self, other: &Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+
+        Rgb::new(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+        )
+    }
+
+    // Distinct bright colours for identifiers (regions...), the same id always gets the same colour
+    pub fn from_id(id: u64) -> Rgb {
+        // Golden ratio hue spacing
+        let hue = (id as f64 * 0.618_033_988_75).fract() * 6.0;
+        let x = 1.0 - (hue % 2.0 - 1.0).abs();
+        let (r, g, b) = match hue as u8 {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x),
+        };
+        let channel = |value: f64| (55.0 + value * 200.0) as u8;
+
+        Rgb::new(channel(r), channel(g), channel(b))
+    }
+}
+
+// Maps the cells of a grid model to colours
+pub trait Palette<T> {
+    fn color(&self, cell: &T) -> Rgb;
+}
+
+impl<T, F: Fn(&T) -> Rgb> Palette<T> for F {
+    fn color(&self, cell: &T) -> Rgb {
+        self(cell)
+    }
+}
+
+pub fn colorize<T>(grid: &Grid<T>, palette: &impl Palette<T>) -> Grid<Rgb> {
+    grid.map(|_, cell| palette.color(cell))
+}
+
+// Paints chosen cells (path, robot...) over an image, positions outside the image are ignored
+pub fn highlight<'a, I>(image: &mut Grid<Rgb>, positions: I, color: Rgb)
+where
+    I: IntoIterator<Item = &'a Vector2d>,
+{
+    for pos in positions {
+        image.set(pos, color);
+    }
+}
+
+// Destination of the images of a visualization (files, terminal...)
+pub trait Canvas {
+    // Picture of the solved puzzle
+    fn still(&mut self, image: &Grid<Rgb>) -> io::Result<()>;
+
+    // Successive states of a simulation
+    fn frame(&mut self, image: &Grid<Rgb>) -> io::Result<()>;
+}
+
+// A day able to draw its puzzle: every visualization ends with a still image,
+// simulations also send their intermediate states as frames
+pub trait Visualize: Solution {
+    fn draw(input: &Self::Input, canvas: &mut dyn Canvas) -> io::Result<()>;
+}
+
+// Object safe version of Visualize, for the registry of the runner
+pub trait Visualizer: Solver {
+    fn visualize(&self, raw_data: &str, params: &Params, canvas: &mut dyn Canvas)
+        -> io::Result<()>;
+}
+
+impl<V: Visualize + Sync> Visualizer for V {
+    fn visualize(
+        &self,
+        raw_data: &str,
+        params: &Params,
+        canvas: &mut dyn Canvas,
+    ) -> io::Result<()> {
+        let input = V::parse_with(raw_data, params).map_err(|err: ParseError| {
+            io::Error::new(io::ErrorKind::InvalidData, err.with_day(V::DAY))
+        })?;
+
+        V::draw(&input, canvas)
+    }
+}
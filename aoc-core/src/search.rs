@@ -0,0 +1,307 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// Distances from the start state, with every predecessor giving the same (best) distance
+#[derive(Clone, Debug)]
+pub struct SearchResult<S> {
+    pub distances: HashMap<S, usize>,
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    fn new(start: S) -> SearchResult<S> {
+        SearchResult {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    // Returns true if the state must be (re)explored
+    fn relax(&mut self, state: &S, next_state: &S, next_distance: usize) -> bool {
+        match self.distances.get(next_state) {
+            Some(distance) if *distance < next_distance => false,
+            Some(distance) if *distance == next_distance => {
+                // Another best path, keep track of it
+                if let Some(predecessors) = self.predecessors.get_mut(next_state) {
+                    predecessors.push(state.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next_state.clone(), next_distance);
+                self.predecessors
+                    .insert(next_state.clone(), vec![state.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    // One of the best paths, from the start state to the given state (both included)
+    pub fn path_to(&self, end: &S) -> Option<Vec<S>> {
+        self.distances.get(end)?;
+
+        let mut path = vec![end.clone()];
+        while let Some(predecessors) = self.predecessors.get(path.last().unwrap()) {
+            path.push(predecessors[0].clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    // All the best paths from the start state to the given state,
+    // the count of paths can grow very quickly: keep it for small graphs
+    pub fn all_paths_to(&self, end: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(end) {
+            return vec![];
+        }
+
+        // Walked backwards from the end state, with the index of the next predecessor to try.
+        // Zero cost moves can make states predecessors of each other: states already on the
+        // path are skipped
+        let mut paths = vec![];
+        let mut path: Vec<(S, usize)> = vec![(end.clone(), 0)];
+        while let Some((state, next_index)) = path.last_mut() {
+            let Some(predecessors) = self.predecessors.get(state) else {
+                // Only the start state has no predecessor
+                paths.push(path.iter().rev().map(|(state, _)| state.clone()).collect());
+                path.pop();
+                continue;
+            };
+            let Some(predecessor) = predecessors.get(*next_index) else {
+                path.pop();
+                continue;
+            };
+            *next_index += 1;
+
+            if !path.iter().any(|(state, _)| state == predecessor) {
+                path.push((predecessor.clone(), 0));
+            }
+        }
+
+        paths
+    }
+
+    // States belonging to at least one of the best paths leading to the given states
+    pub fn best_path_states(&self, ends: &[S]) -> HashSet<S> {
+        let mut states = HashSet::new();
+
+        let mut remaining_states: Vec<S> = ends
+            .iter()
+            .filter(|end| self.distances.contains_key(end))
+            .cloned()
+            .collect();
+
+        while let Some(state) = remaining_states.pop() {
+            if let Some(predecessors) = self.predecessors.get(&state) {
+                for predecessor in predecessors {
+                    if !states.contains(predecessor) {
+                        remaining_states.push(predecessor.clone());
+                    }
+                }
+            }
+
+            states.insert(state);
+        }
+
+        states
+    }
+}
+
+// Breadth-first search, every move costs 1
+pub fn bfs<S, I, N>(start: S, mut neighbours: N) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+{
+    let mut result = SearchResult::new(start.clone());
+
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        for next_state in neighbours(&state) {
+            if result.relax(&state, &next_state, distance + 1) {
+                queue.push_back((next_state, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+// Dijkstra search, neighbours are given with the cost of the move
+pub fn dijkstra<S, I, N>(start: S, mut neighbours: N) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    N: FnMut(&S) -> I,
+{
+    let mut result = SearchResult::new(start.clone());
+
+    // States are stored aside, so they don't need to be ordered
+    let mut states = vec![start];
+    let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+    while let Some(Reverse((distance, index))) = queue.pop() {
+        let state = states[index].clone();
+        if distance > result.distances[&state] {
+            continue; // Outdated entry
+        }
+
+        for (next_state, cost) in neighbours(&state) {
+            if result.relax(&state, &next_state, distance + cost) {
+                states.push(next_state);
+                queue.push(Reverse((distance + cost, states.len() - 1)));
+            }
+        }
+    }
+
+    result
+}
+
+// A* search, stops on the first goal state reached. The heuristic must never overestimate
+// the remaining cost. Returns the path (start and goal included) and its cost
+pub fn astar<S, I, N, H, G>(
+    start: S,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    N: FnMut(&S) -> I,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new(start.clone());
+
+    let mut states = vec![start.clone()];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    while let Some(Reverse((_, distance, index))) = queue.pop() {
+        let state = states[index].clone();
+        if distance > result.distances[&state] {
+            continue; // Outdated entry
+        }
+
+        if is_goal(&state) {
+            let path = result.path_to(&state)?;
+            return Some((path, distance));
+        }
+
+        for (next_state, cost) in neighbours(&state) {
+            let next_distance = distance + cost;
+            if result.relax(&state, &next_state, next_distance) {
+                let estimation = next_distance + heuristic(&next_state);
+                states.push(next_state);
+                queue.push(Reverse((estimation, next_distance, states.len() - 1)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Weighted graph with two best paths from 'a' to 'd' (through 'b' or 'c'), and a longer
+    // direct move
+    fn diamond(state: &char) -> Vec<(char, usize)> {
+        match state {
+            'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+            'b' => vec![('d', 3)],
+            'c' => vec![('d', 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_the_moves() {
+        // Numbers 0 to 20, moving by +1 or *2
+        let result = bfs(1, |&n: &u32| {
+            [n + 1, n * 2].into_iter().filter(|&next| next <= 20)
+        });
+
+        assert_eq!(result.distance(&1), Some(0));
+        assert_eq!(result.distance(&16), Some(4));
+        assert_eq!(result.distance(&11), Some(5));
+        assert_eq!(result.distance(&0), None);
+        assert_eq!(result.path_to(&16), Some(vec![1, 2, 4, 8, 16]));
+        assert_eq!(result.path_to(&0), None);
+    }
+
+    #[test]
+    fn dijkstra_keeps_every_best_path() {
+        let result = dijkstra('a', diamond);
+
+        assert_eq!(result.distance(&'d'), Some(4));
+        assert_eq!(result.path_to(&'a'), Some(vec!['a']));
+        assert!([vec!['a', 'b', 'd'], vec!['a', 'c', 'd']].contains(&result.path_to(&'d').unwrap()));
+
+        let mut paths = result.all_paths_to(&'d');
+        paths.sort();
+        assert_eq!(paths, [vec!['a', 'b', 'd'], vec!['a', 'c', 'd']]);
+        assert!(result.all_paths_to(&'z').is_empty());
+
+        assert_eq!(
+            result.best_path_states(&['d']),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+        assert_eq!(
+            result.best_path_states(&['c', 'z']),
+            HashSet::from(['a', 'c'])
+        );
+    }
+
+    #[test]
+    fn zero_cost_cycles_end() {
+        // 'b' and 'c' are both predecessors of each other
+        let result = dijkstra('a', |state: &char| match state {
+            'a' => vec![('b', 1), ('c', 1)],
+            'b' => vec![('c', 0), ('d', 1)],
+            'c' => vec![('b', 0)],
+            _ => vec![],
+        });
+
+        let mut paths = result.all_paths_to(&'d');
+        paths.sort();
+        assert_eq!(paths, [vec!['a', 'b', 'd'], vec!['a', 'c', 'b', 'd']]);
+        assert_eq!(
+            result.best_path_states(&['d']),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+    }
+
+    #[test]
+    fn astar_finds_a_cheapest_path() {
+        assert_eq!(
+            astar('a', diamond, |_| 0, |state| *state == 'd').map(|(_, cost)| cost),
+            Some(4)
+        );
+        assert_eq!(astar('a', diamond, |_| 0, |state| *state == 'z'), None);
+
+        // Grid of 10x10 with a wall on x = 5 (except at y = 9), Manhattan distance heuristic
+        let goal = (9_i32, 0_i32);
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .filter(|&(x, y)| x != 5 || y == 9)
+                .map(|next| (next, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as usize;
+        let (path, cost) = astar((0, 0), neighbours, heuristic, |state| *state == goal).unwrap();
+
+        assert_eq!(cost, 27);
+        assert_eq!(path.len(), 28);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.contains(&(5, 9)));
+    }
+}
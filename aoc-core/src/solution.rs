@@ -0,0 +1,174 @@
+use crate::config::{Param, Params};
+use crate::error::ParseError;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// Answer of a puzzle part: most of them are numbers, some are text (program output, coordinates...)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+// Numbers which don't fit in an i64 are kept as text rather than wrapped
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Number(value),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+// A day of the calendar: the input is parsed once, then shared by both parts
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    // Puzzle parameters (grid size, count of steps...), which can be changed by the configuration
+    const PARAMS: &'static [Param] = &[];
+
+    type Input;
+
+    fn parse(raw_data: &str) -> Result<Self::Input, ParseError>;
+
+    // Days with parameters keep them in their input, the others ignore them
+    fn parse_with(raw_data: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(raw_data)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+// Answer of a single part, with the time spent to solve it
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+// Parse once, then solve the requested parts
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+// Object safe version of Solution, so that all the days can be stored in a registry
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn params(&self) -> &'static [Param];
+    fn run(&self, raw_data: &str, parts: &[u8], params: &Params) -> Result<Run, ParseError>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn run(&self, raw_data: &str, parts: &[u8], params: &Params) -> Result<Run, ParseError> {
+        let _day_span = tracing::info_span!("day", day = S::DAY).entered();
+
+        let start = Instant::now();
+        let input = tracing::info_span!("parse", bytes = raw_data.len())
+            .in_scope(|| S::parse_with(raw_data, params))
+            .map_err(|err| err.with_day(S::DAY));
+        let parse_time = start.elapsed();
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                tracing::debug!(error = %err, "invalid input");
+                return Err(err);
+            }
+        };
+        tracing::debug!(elapsed = ?parse_time, "parsed");
+
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let _part_span = tracing::info_span!("part", part).entered();
+
+                let start = Instant::now();
+                let answer = match part {
+                    1 => S::part1(&input),
+                    2 => S::part2(&input),
+                    _ => panic!("Part {part} does not exist"),
+                };
+                let elapsed = start.elapsed();
+                tracing::debug!(%answer, ?elapsed, "solved");
+
+                PartRun {
+                    part: *part,
+                    answer,
+                    elapsed,
+                }
+            })
+            .collect();
+
+        Ok(Run { parse_time, parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_numbers_are_not_wrapped() {
+        assert_eq!(Answer::from(-3), Answer::Number(-3));
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+    }
+}
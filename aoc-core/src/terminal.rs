@@ -0,0 +1,112 @@
+use crate::grid::Grid;
+use crate::render::{Canvas, Rgb};
+use crate::vector::Vector2d;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Upper half block: the foreground colour draws the upper cell, the background the lower one
+const HALF_BLOCK: char = '▀';
+
+// Draws an image with 24 bits colours, two rows of cells per line of text
+pub fn render(image: &Grid<Rgb>) -> String {
+    let mut text = String::new();
+
+    for y in (0..image.height() as i64).step_by(2) {
+        let mut current_colors = None;
+        for x in 0..image.width() as i64 {
+            let upper = image[Vector2d::new(x, y)];
+            let lower = image
+                .get(&Vector2d::new(x, y + 1))
+                .copied()
+                .unwrap_or_default();
+
+            // Escape sequences are only written when the colours change
+            if current_colors != Some((upper, lower)) {
+                let _ = write!(
+                    text,
+                    "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m",
+                    upper.r, upper.g, upper.b, lower.r, lower.g, lower.b
+                );
+                current_colors = Some((upper, lower));
+            }
+            text.push(HALF_BLOCK);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+
+    text
+}
+
+// Draws the images in the terminal. Without animation, only the still image is drawn,
+// otherwise frames are redrawn in place at a fixed rate (one frame out of `every`)
+pub struct TerminalCanvas<W: Write> {
+    out: W,
+    animate: bool,
+    frame_delay: Duration,
+    every: usize,
+    frame_count: usize,
+    last_draw: Option<Instant>,
+    drawn_lines: usize,
+}
+
+impl<W: Write> TerminalCanvas<W> {
+    pub fn new(out: W, animate: bool, fps: u32, every: usize) -> TerminalCanvas<W> {
+        TerminalCanvas {
+            out,
+            animate,
+            frame_delay: Duration::from_secs(1) / fps.max(1),
+            every: every.max(1),
+            frame_count: 0,
+            last_draw: None,
+            drawn_lines: 0,
+        }
+    }
+
+    fn draw(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        if let Some(last_draw) = self.last_draw {
+            let elapsed = last_draw.elapsed();
+            if elapsed < self.frame_delay {
+                thread::sleep(self.frame_delay - elapsed);
+            }
+        }
+
+        // Moves the cursor back to the start of the previous image
+        let mut text = match self.drawn_lines {
+            0 => String::new(),
+            lines => format!("\x1b[{lines}A"),
+        };
+        text.push_str(&render(image));
+
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+
+        self.last_draw = Some(Instant::now());
+        self.drawn_lines = image.height().div_ceil(2);
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Canvas for TerminalCanvas<W> {
+    fn still(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        self.draw(image)?;
+
+        // Next images are drawn below this one
+        self.last_draw = None;
+        self.drawn_lines = 0;
+
+        Ok(())
+    }
+
+    fn frame(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        let index = self.frame_count;
+        self.frame_count += 1;
+        if !self.animate || !index.is_multiple_of(self.every) {
+            return Ok(());
+        }
+
+        self.draw(image)
+    }
+}
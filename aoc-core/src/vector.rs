@@ -0,0 +1,246 @@
+use crate::direction::Direction;
+use std::fmt;
+use std::ops;
+
+// Numeric types which can be used as vector coordinates
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+{
+    const ZERO: Self;
+
+    fn abs_diff(self, other: Self) -> usize;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0;
+
+                fn abs_diff(self, other: Self) -> usize {
+                    <$t>::abs_diff(self, other) as usize
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i32, i64, isize, u32, u64, usize);
+
+// Coordinates default to i64, which is what most grids use
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2d<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector2d<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vector2d { x, y }
+    }
+
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vector2d<U>> {
+        let x = U::try_from(self.x).ok()?;
+        let y = U::try_from(self.y).ok()?;
+
+        Some(Vector2d { x, y })
+    }
+}
+
+impl<T: Scalar> Vector2d<T> {
+    pub fn zero() -> Self {
+        Vector2d {
+            x: T::ZERO,
+            y: T::ZERO,
+        }
+    }
+
+    pub fn dot_product(&self, other: &Vector2d<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    // Manhattan distance
+    pub fn distance(&self, other: &Vector2d<T>) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        let x = self.x.to_f64();
+        let y = self.y.to_f64();
+        (x * x + y * y).sqrt()
+    }
+}
+
+impl<T: Scalar + ops::Neg<Output = T>> Vector2d<T> {
+    // Clockwise rotation, y axis pointing down
+    pub fn rotate_right(&self) -> Vector2d<T> {
+        Vector2d {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    // Counterclockwise rotation, y axis pointing down
+    pub fn rotate_left(&self) -> Vector2d<T> {
+        Vector2d {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl Vector2d<usize> {
+    pub fn checked_add_signed(self, delta: Vector2d<isize>) -> Option<Vector2d<usize>> {
+        let x = self.x.checked_add_signed(delta.x)?;
+        let y = self.y.checked_add_signed(delta.y)?;
+
+        Some(Vector2d { x, y })
+    }
+}
+
+impl<T: Scalar> ops::Add<Vector2d<T>> for Vector2d<T> {
+    type Output = Vector2d<T>;
+
+    fn add(self, other: Vector2d<T>) -> Vector2d<T> {
+        Vector2d {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: Scalar + From<i8>> ops::Add<Direction> for Vector2d<T> {
+    type Output = Vector2d<T>;
+
+    fn add(self, direction: Direction) -> Vector2d<T> {
+        self + direction.to_vector()
+    }
+}
+
+impl<T: Scalar> ops::AddAssign<Vector2d<T>> for Vector2d<T> {
+    fn add_assign(&mut self, other: Vector2d<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Scalar> ops::Sub<Vector2d<T>> for Vector2d<T> {
+    type Output = Vector2d<T>;
+
+    fn sub(self, other: Vector2d<T>) -> Vector2d<T> {
+        Vector2d {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Scalar> ops::SubAssign<Vector2d<T>> for Vector2d<T> {
+    fn sub_assign(&mut self, other: Vector2d<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for Vector2d<T> {
+    type Output = Vector2d<T>;
+
+    fn mul(self, rhs: T) -> Vector2d<T> {
+        Vector2d {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for Vector2d<T> {
+    type Output = Vector2d<T>;
+
+    fn neg(self) -> Vector2d<T> {
+        Vector2d {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vector2d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vector2d::new(3, -4);
+        let b = Vector2d::new(-1, 2);
+
+        assert_eq!(a + b, Vector2d::new(2, -2));
+        assert_eq!(a - b, Vector2d::new(4, -6));
+        assert_eq!(a * 3, Vector2d::new(9, -12));
+        assert_eq!(-a, Vector2d::new(-3, 4));
+        assert_eq!(a.dot_product(&b), -11);
+        assert_eq!(a + Direction::Up, Vector2d::new(3, -5));
+        assert_eq!(Vector2d::<i64>::zero(), Vector2d::default());
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn distance_and_magnitude() {
+        let a = Vector2d::new(3, -4);
+
+        assert_eq!(a.distance(&Vector2d::zero()), 7);
+        assert_eq!(a.distance(&Vector2d::new(-3, 4)), 14);
+        assert_eq!(a.magnitude(), 5.0);
+        assert_eq!(Vector2d::new(2_usize, 9).distance(&Vector2d::new(7, 1)), 13);
+    }
+
+    #[test]
+    fn rotations_follow_the_y_down_convention() {
+        let up = Vector2d::new(0, -1);
+
+        assert_eq!(up.rotate_right(), Vector2d::new(1, 0));
+        assert_eq!(up.rotate_left(), Vector2d::new(-1, 0));
+        assert_eq!(up.rotate_right().rotate_right(), -up);
+        assert_eq!(up.rotate_left().rotate_right(), up);
+    }
+
+    #[test]
+    fn checked_conversions() {
+        assert_eq!(
+            Vector2d::new(3_i64, 4).try_cast::<usize>(),
+            Some(Vector2d::new(3_usize, 4))
+        );
+        assert_eq!(Vector2d::new(3_i64, -4).try_cast::<usize>(), None);
+        assert_eq!(Vector2d::new(300_i64, 4).try_cast::<u8>(), None);
+
+        let pos = Vector2d::new(2_usize, 0);
+        assert_eq!(
+            pos.checked_add_signed(Vector2d::new(-2, 3)),
+            Some(Vector2d::new(0, 3))
+        );
+        assert_eq!(pos.checked_add_signed(Vector2d::new(0, -1)), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Vector2d::new(6, -1).to_string(), "(6,-1)");
+    }
+}
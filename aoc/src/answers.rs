@@ -0,0 +1,111 @@
+use aoc_core::input;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Inputs of the default profile are inputs/dayNN.txt, other profiles (another account,
+// a tricky input...) have their own directory: inputs/<profile>/dayNN.txt
+pub const DEFAULT_PROFILE: &str = "default";
+
+pub fn default_answers_path() -> PathBuf {
+    input::inputs_dir().join("answers.json")
+}
+
+pub fn input_path(day: u8, profile: &str) -> PathBuf {
+    match profile {
+        DEFAULT_PROFILE => input::default_input_path(day),
+        _ => input::inputs_dir()
+            .join(profile)
+            .join(format!("day{day:02}.txt")),
+    }
+}
+
+// Known correct answers, as displayed by the solvers
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+
+    fn get_mut(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+// Answers by profile, then by day
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    profiles: BTreeMap<String, BTreeMap<u8, DayAnswers>>,
+}
+
+impl AnswerStore {
+    // A missing file is an empty store
+    pub fn load(path: &Path) -> io::Result<AnswerStore> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(io::Error::from),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn get(&self, profile: &str, day: u8, part: u8) -> Option<&String> {
+        self.profiles.get(profile)?.get(&day)?.get(part)
+    }
+
+    // Never overwrites a known answer, returns true if the answer was added
+    pub fn record(&mut self, profile: &str, day: u8, part: u8, answer: String) -> bool {
+        let stored = self
+            .profiles
+            .entry(profile.to_string())
+            .or_default()
+            .entry(day)
+            .or_default()
+            .get_mut(part);
+
+        match stored {
+            Some(_) => false,
+            None => {
+                *stored = Some(answer);
+                true
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+pub fn check(store: &AnswerStore, profile: &str, day: u8, part: u8, answer: &str) -> Verdict {
+    match store.get(profile, day, part) {
+        Some(expected) if expected == answer => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.clone(),
+        },
+        None => Verdict::Missing,
+    }
+}
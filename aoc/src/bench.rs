@@ -0,0 +1,160 @@
+use crate::report::format_duration;
+use aoc_core::{Params, ParseError, Solver};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+// Durations are stored in nanoseconds, so baselines stay readable
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct PhaseStats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl PhaseStats {
+    fn new(mut samples: Vec<Duration>) -> PhaseStats {
+        samples.sort();
+        let nanos = |duration: &Duration| duration.as_nanos() as u64;
+
+        PhaseStats {
+            min_ns: nanos(&samples[0]),
+            median_ns: nanos(&samples[samples.len() / 2]),
+            max_ns: nanos(&samples[samples.len() - 1]),
+        }
+    }
+}
+
+// Phases are "parse", "part1" and "part2"
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub title: String,
+    pub phases: BTreeMap<String, PhaseStats>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BenchReport {
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> io::Result<BenchReport> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(io::Error::from)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+    }
+
+    fn phase(&self, day: u8, phase: &str) -> Option<&PhaseStats> {
+        self.days
+            .iter()
+            .find(|day_bench| day_bench.day == day)
+            .and_then(|day_bench| day_bench.phases.get(phase))
+    }
+}
+
+pub fn bench_day(
+    solver: &dyn Solver,
+    raw_data: &str,
+    parts: &[u8],
+    params: &Params,
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    let mut samples: BTreeMap<String, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..iterations {
+        let run = solver.run(raw_data, parts, params)?;
+
+        samples
+            .entry("parse".to_string())
+            .or_default()
+            .push(run.parse_time);
+        for part_run in run.parts {
+            samples
+                .entry(format!("part{}", part_run.part))
+                .or_default()
+                .push(part_run.elapsed);
+        }
+    }
+
+    Ok(DayBench {
+        day: solver.day(),
+        title: solver.title().to_string(),
+        phases: samples
+            .into_iter()
+            .map(|(phase, samples)| (phase, PhaseStats::new(samples)))
+            .collect(),
+    })
+}
+
+// Relative change of the median time, in percent
+fn median_change(stats: &PhaseStats, baseline: &PhaseStats) -> f64 {
+    let baseline_median = baseline.median_ns.max(1) as f64;
+    (stats.median_ns as f64 - baseline_median) / baseline_median * 100.0
+}
+
+// Prints the report, compared to the baseline if any.
+// Returns the count of phases slower than the baseline by more than the threshold
+pub fn print_report(report: &BenchReport, baseline: Option<&BenchReport>, threshold: f64) -> usize {
+    let nanos = Duration::from_nanos;
+    let title_width = report
+        .days
+        .iter()
+        .map(|day_bench| day_bench.title.chars().count())
+        .chain(["Title".len()])
+        .max()
+        .unwrap();
+
+    print!(
+        "{:>3}  {:<title_width$}  {:<5}  {:>9}  {:>9}  {:>9}",
+        "Day", "Title", "Phase", "Min", "Median", "Max"
+    );
+    match baseline {
+        Some(_) => println!("  {:>9}  {:>8}", "Baseline", "Change"),
+        None => println!(),
+    }
+
+    let mut regressions = 0;
+    for day_bench in &report.days {
+        for (phase, stats) in &day_bench.phases {
+            print!(
+                "{:>3}  {:<title_width$}  {:<5}  {:>9}  {:>9}  {:>9}",
+                day_bench.day,
+                day_bench.title,
+                phase,
+                format_duration(nanos(stats.min_ns)),
+                format_duration(nanos(stats.median_ns)),
+                format_duration(nanos(stats.max_ns))
+            );
+
+            let baseline_stats = baseline.and_then(|baseline| baseline.phase(day_bench.day, phase));
+            match baseline_stats {
+                Some(baseline_stats) => {
+                    let change = median_change(stats, baseline_stats);
+                    let slower = change > threshold;
+                    if slower {
+                        regressions += 1;
+                    }
+
+                    println!(
+                        "  {:>9}  {:>+7.1}%{}",
+                        format_duration(nanos(baseline_stats.median_ns)),
+                        change,
+                        if slower { "  SLOWER" } else { "" }
+                    );
+                }
+                None if baseline.is_some() => println!("  {:>9}  {:>8}", "-", "-"),
+                None => println!(),
+            }
+        }
+    }
+
+    regressions
+}
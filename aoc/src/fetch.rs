@@ -0,0 +1,116 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2024";
+
+// Identifies the tool to the website, as asked by its automation guidelines
+const USER_AGENT: &str = "github.com/ehorrent/AdventOfCode2024 (aoc fetch)";
+
+// Too many requests answers are retried a few times, after the delay given by the server
+const MAX_RETRIES: usize = 3;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+// Downloads puzzle inputs, with at least `delay` between two requests
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    delay: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, delay: Duration) -> Fetcher {
+        Fetcher {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            delay,
+            last_request: None,
+        }
+    }
+
+    fn wait_turn(&mut self, delay: Duration) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < delay {
+                thread::sleep(delay - elapsed);
+            }
+        }
+
+        self.last_request = Some(Instant::now());
+    }
+
+    fn download(&mut self, day: u8) -> io::Result<String> {
+        let url = format!("{}/day/{day}/input", self.base_url);
+
+        let mut delay = self.delay;
+        for _ in 0..=MAX_RETRIES {
+            self.wait_turn(delay);
+
+            let response = self
+                .agent
+                .get(&url)
+                .set("Cookie", &format!("session={}", self.session))
+                .call();
+
+            match response {
+                Ok(response) => return response.into_string(),
+                Err(ureq::Error::Status(429, response)) => {
+                    let retry_after = response
+                        .header("Retry-After")
+                        .and_then(|seconds| seconds.parse::<u64>().ok());
+                    delay = retry_after.map_or(self.delay, Duration::from_secs);
+                }
+                Err(ureq::Error::Status(404, _)) => {
+                    return Err(io::Error::other(format!(
+                        "{url}: no input (the puzzle may not be unlocked yet)"
+                    )));
+                }
+                Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(500, _)) => {
+                    return Err(io::Error::other(format!(
+                        "{url}: input refused, the session token may be invalid or expired"
+                    )));
+                }
+                Err(err) => return Err(io::Error::other(format!("{url}: {err}"))),
+            }
+        }
+
+        Err(io::Error::other(format!(
+            "{url}: still rate limited after {MAX_RETRIES} retries"
+        )))
+    }
+
+    // A cached input is never downloaded again
+    pub fn fetch(&mut self, day: u8, path: &Path) -> io::Result<Fetched> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let raw_data = self.download(day)?;
+        if raw_data.is_empty() {
+            return Err(io::Error::other(format!("Day {day}: empty input")));
+        }
+
+        // Written aside first, an interrupted download must not look cached
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let partial_path = path.with_extension("part");
+        fs::write(&partial_path, raw_data)?;
+        fs::rename(&partial_path, path)?;
+
+        Ok(Fetched::Downloaded)
+    }
+}
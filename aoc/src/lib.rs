@@ -0,0 +1,117 @@
+#[cfg(feature = "serde")]
+use aoc_core::Dumper;
+use aoc_core::{Generator, Solver, Visualizer};
+
+// Every implemented day, in calendar order
+static SOLUTIONS: [&dyn Solver; 21] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+];
+
+pub fn solutions() -> &'static [&'static dyn Solver] {
+    &SOLUTIONS
+}
+
+pub fn solution(day: u8) -> Option<&'static dyn Solver> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
+
+// Days which can draw their puzzle
+static VISUALIZATIONS: [&dyn Visualizer; 6] = [
+    &day6::Day6,
+    &day12::Day12,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day20::Day20,
+];
+
+pub fn visualizations() -> &'static [&'static dyn Visualizer] {
+    &VISUALIZATIONS
+}
+
+pub fn visualization(day: u8) -> Option<&'static dyn Visualizer> {
+    VISUALIZATIONS
+        .iter()
+        .find(|visualization| visualization.day() == day)
+        .copied()
+}
+
+// Days which can write their parsed puzzle as JSON
+#[cfg(feature = "serde")]
+static DUMPS: [&dyn Dumper; 6] = [
+    &day5::Day5,
+    &day9::Day9,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day17::Day17,
+];
+
+#[cfg(feature = "serde")]
+pub fn dumps() -> &'static [&'static dyn Dumper] {
+    &DUMPS
+}
+
+#[cfg(feature = "serde")]
+pub fn dump(day: u8) -> Option<&'static dyn Dumper> {
+    DUMPS.iter().find(|dump| dump.day() == day).copied()
+}
+
+// Days which can build random puzzle inputs
+static GENERATORS: [&dyn Generator; 21] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+];
+
+pub fn generators() -> &'static [&'static dyn Generator] {
+    &GENERATORS
+}
+
+pub fn generator(day: u8) -> Option<&'static dyn Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.day() == day)
+        .copied()
+}
@@ -0,0 +1,1033 @@
+mod answers;
+mod bench;
+mod fetch;
+mod pool;
+mod report;
+mod watch;
+
+use answers::{AnswerStore, Verdict};
+use aoc_core::terminal::TerminalCanvas;
+use aoc_core::{config, input, Answer, Config, Difficulty, Params, Run, Solver};
+#[cfg(feature = "png")]
+use aoc_core::{png::PngCanvas, Visualizer};
+use bench::BenchReport;
+use clap::{ArgAction, Parser, Subcommand};
+use fetch::{Fetched, Fetcher};
+use report::{Format, Row};
+#[cfg(feature = "serde")]
+use std::fs;
+#[cfg(feature = "serde")]
+use std::io::Write;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::EnvFilter;
+use watch::WatchedFile;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    /// Diagnostics on stderr: -v for info, -vv for debug, -vvv for trace
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Diagnostics filter, added to -v (e.g. "day17=debug", "day15=trace,aoc_core=info")
+    #[arg(long, global = true, env = "RUST_LOG")]
+    log: Option<String>,
+
+    /// Puzzle parameters file, with a section by day (defaults to aoc.toml if it exists)
+    #[arg(long, global = true, env = "AOC_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Change a puzzle parameter, e.g. "day18.width=7" (can be repeated)
+    #[arg(long = "set", global = true, value_name = "DAY.NAME=VALUE")]
+    overrides: Vec<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the implemented days
+    List,
+
+    /// Solve a single day, or the whole calendar
+    Run {
+        /// Day to solve
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Solve all the days
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file, "-" for stdin (defaults to inputs/dayNN.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Days solved at the same time (defaults to the count of CPUs)
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+    },
+
+    /// Time parse, part 1 and part 2 of a day, or of the whole calendar
+    Bench {
+        /// Day to benchmark
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Benchmark all the days
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+
+        /// Only benchmark this part (parsing is always timed)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file, "-" for stdin (defaults to inputs/dayNN.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Runs of each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Save the results to this JSON file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare the results to this JSON file (saved by a previous run)
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Median slow down (in percent) over the baseline reported as a failure
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+
+    /// Check the answers of a day, or of the whole calendar, against the known ones
+    Verify {
+        /// Day to verify
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Verify all the days
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+
+        /// Only verify this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input profile: "default" uses inputs/dayNN.txt, others inputs/<PROFILE>/dayNN.txt
+        #[arg(long, default_value = answers::DEFAULT_PROFILE)]
+        profile: String,
+
+        /// Known answers file (defaults to inputs/answers.json)
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// Store the answers which are not known yet
+        #[arg(long)]
+        record: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+
+    /// Solve a day again every time one of its inputs (or the configuration) changes
+    ///
+    /// Source files are not watched: the solvers are the ones built into this binary, changes of
+    /// the code need a rebuild and a restart of the command (e.g. with cargo-watch).
+    Watch {
+        /// Day to solve
+        day: u8,
+
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file to watch, can be repeated (defaults to inputs/dayNN.txt)
+        #[arg(long)]
+        input: Vec<PathBuf>,
+
+        /// Delay between two checks of the files, in milliseconds
+        #[arg(long, default_value_t = 200, value_parser = clap::value_parser!(u64).range(1..))]
+        interval_ms: u64,
+    },
+
+    /// Download puzzle inputs to the inputs directory (cached inputs are kept)
+    Fetch {
+        /// Day to download
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Download the inputs of all the days
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+
+        /// Session cookie of the website
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+
+        /// Website of the event
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Minimal delay between two requests, in milliseconds
+        #[arg(long, default_value_t = 3000)]
+        delay_ms: u64,
+    },
+
+    /// Draw the puzzle of a day in the terminal (or as PNG images)
+    Visualize {
+        /// Day to draw
+        day: u8,
+
+        /// Puzzle input file, "-" for stdin (defaults to inputs/dayNN.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Watch the simulation in the terminal before the final picture
+        #[arg(long)]
+        animate: bool,
+
+        /// Frames per second of the animation
+        #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..=1000))]
+        fps: u32,
+
+        /// Only keep one frame out of N
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+
+        /// Write PNG images to this directory instead of drawing in the terminal
+        #[cfg(feature = "png")]
+        #[arg(long, conflicts_with = "animate")]
+        output: Option<PathBuf>,
+
+        /// Size of a grid cell in the PNG images, in pixels
+        #[cfg(feature = "png")]
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+    },
+
+    /// Write the parsed puzzle of a day as JSON
+    #[cfg(feature = "serde")]
+    Dump {
+        /// Day of the puzzle
+        day: u8,
+
+        /// Puzzle input file, "-" for stdin (defaults to inputs/dayNN.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// JSON file to write (defaults to the standard output)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Print a random puzzle input for a day
+    Generate {
+        /// Day of the puzzle
+        day: u8,
+
+        /// Size of the input, its meaning depends on the day (defaults to the real input size)
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Difficulty: easy, normal or hard
+        #[arg(long, default_value_t)]
+        difficulty: Difficulty,
+
+        /// Seed of the random values, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+// Days with their parameters, as set by the configuration
+fn list(config: &Config) -> ExitCode {
+    for solver in aoc::solutions() {
+        let params = match day_params(*solver, config) {
+            Ok(params) => params,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let params: Vec<String> = solver
+            .params()
+            .iter()
+            .map(|param| format!("{}={}", param.name, params.get::<u64>(param.name)))
+            .collect();
+
+        match params.is_empty() {
+            true => println!("{:>3}  {}", solver.day(), solver.title()),
+            false => println!(
+                "{:>3}  {:<24} {}",
+                solver.day(),
+                solver.title(),
+                params.join(" ")
+            ),
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Errors are returned as messages, ready to be displayed
+fn run_day(
+    solver: &dyn Solver,
+    parts: &[u8],
+    input: Option<&Path>,
+    config: &Config,
+) -> Result<Vec<Row>, String> {
+    let params = day_params(solver, config)?;
+    let raw_data = input::read_input(solver.day(), input)
+        .map_err(|err| format!("Day {}: {err}", solver.day()))?;
+
+    // Parse errors already tell the day
+    let run = solver
+        .run(&raw_data, parts, &params)
+        .map_err(|err| err.to_string())?;
+
+    let input_hash = report::input_hash(&raw_data);
+    let rows = run
+        .parts
+        .into_iter()
+        .map(|part_run| Row {
+            day: solver.day(),
+            title: solver.title(),
+            part: part_run.part,
+            answer: part_run.answer,
+            elapsed: part_run.elapsed,
+            input_hash: input_hash.clone(),
+            status: None,
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn day_params(solver: &dyn Solver, config: &Config) -> Result<Params, String> {
+    config
+        .params(solver.day(), solver.params())
+        .map_err(|err| format!("Day {}: {err}", solver.day()))
+}
+
+// Configuration file, then the --set overrides
+fn load_config(path: Option<&Path>, overrides: &[String]) -> Result<Config, String> {
+    let mut config = Config::load(path)?;
+    for assignment in overrides {
+        config.set(assignment)?;
+    }
+
+    Ok(config)
+}
+
+fn select_solvers(day: Option<u8>, all: bool) -> Option<Vec<&'static dyn Solver>> {
+    match (all, day) {
+        (true, _) => Some(aoc::solutions().to_vec()),
+        (false, Some(day)) => match aoc::solution(day) {
+            Some(solver) => Some(vec![solver]),
+            None => {
+                eprintln!("Day {day} is not implemented");
+                None
+            }
+        },
+        (false, None) => unreachable!("clap requires a day when --all is not set"),
+    }
+}
+
+fn parts_to_run(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+// Failed days are reported with one row per part, the status tells why
+fn failure_rows(solver: &dyn Solver, parts: &[u8], status: &str) -> Vec<Row> {
+    parts
+        .iter()
+        .map(|part| Row {
+            day: solver.day(),
+            title: solver.title(),
+            part: *part,
+            answer: Answer::Unsolved,
+            elapsed: Duration::ZERO,
+            input_hash: String::new(),
+            status: Some(status.to_string()),
+        })
+        .collect()
+}
+
+fn run(
+    day: Option<u8>,
+    all: bool,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    format: Format,
+    jobs: usize,
+    config: &Config,
+) -> ExitCode {
+    let parts = parts_to_run(part);
+
+    let Some(solvers) = select_solvers(day, all) else {
+        return ExitCode::FAILURE;
+    };
+
+    let start = Instant::now();
+    let results = pool::run_jobs(&solvers, jobs, |solver| {
+        let start = Instant::now();
+        let day_rows = run_day(*solver, &parts, input.as_deref(), config);
+        (day_rows, start.elapsed())
+    });
+    let wall_time = start.elapsed();
+
+    let mut rows = vec![];
+    let mut failures = 0;
+    let mut days_time = Duration::ZERO;
+    for (solver, result) in solvers.iter().zip(results) {
+        let status = match result {
+            Ok((Ok(day_rows), elapsed)) => {
+                rows.extend(day_rows);
+                days_time += elapsed;
+                continue;
+            }
+            Ok((Err(err), _)) => format!("ERROR: {err}"),
+            Err(message) => format!("PANIC: {message}"),
+        };
+
+        rows.extend(failure_rows(*solver, &parts, &status));
+        failures += 1;
+    }
+
+    // Machine readable formats keep the summary out of the standard output
+    report::print_rows(&rows, format);
+    let summary = format!(
+        "{} day(s), {failures} failed, wall time {} ({} summed over the days)",
+        solvers.len(),
+        report::format_duration(wall_time),
+        report::format_duration(days_time)
+    );
+    match format {
+        Format::Table => println!("{summary}"),
+        _ => eprintln!("{summary}"),
+    }
+
+    match failures {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+struct BenchOptions {
+    iterations: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn bench(
+    day: Option<u8>,
+    all: bool,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    options: BenchOptions,
+    config: &Config,
+) -> ExitCode {
+    let parts = parts_to_run(part);
+
+    let Some(solvers) = select_solvers(day, all) else {
+        return ExitCode::FAILURE;
+    };
+
+    // Read the baseline first, a wrong path must not waste a whole benchmark
+    let baseline = match options.baseline.as_deref().map(BenchReport::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(err)) => {
+            eprintln!("Can't read baseline file: {err}");
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    let mut report = BenchReport {
+        iterations: options.iterations,
+        days: vec![],
+    };
+    let mut failures = 0;
+    for solver in solvers {
+        let params = match day_params(solver, config) {
+            Ok(params) => params,
+            Err(err) => {
+                eprintln!("{err}");
+                failures += 1;
+                continue;
+            }
+        };
+        let raw_data = match input::read_input(solver.day(), input.as_deref()) {
+            Ok(raw_data) => raw_data,
+            Err(err) => {
+                eprintln!("Day {}: {err}", solver.day());
+                failures += 1;
+                continue;
+            }
+        };
+
+        match bench::bench_day(solver, &raw_data, &parts, &params, options.iterations) {
+            Ok(day_bench) => report.days.push(day_bench),
+            Err(err) => {
+                eprintln!("{err}");
+                failures += 1;
+            }
+        }
+    }
+
+    let regressions = bench::print_report(&report, baseline.as_ref(), options.threshold);
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} phase(s) slower than the baseline by more than {}%",
+            options.threshold
+        );
+    }
+
+    if let Some(path) = &options.save {
+        if let Err(err) = report.save(path) {
+            eprintln!("Can't save benchmark to {}: {err}", path.display());
+            failures += 1;
+        }
+    }
+
+    match failures + regressions {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+struct VerifyOptions {
+    profile: String,
+    answers: Option<PathBuf>,
+    record: bool,
+    format: Format,
+}
+
+fn verify(
+    day: Option<u8>,
+    all: bool,
+    part: Option<u8>,
+    options: VerifyOptions,
+    config: &Config,
+) -> ExitCode {
+    let parts = parts_to_run(part);
+
+    let Some(solvers) = select_solvers(day, all) else {
+        return ExitCode::FAILURE;
+    };
+
+    let answers_path = options
+        .answers
+        .unwrap_or_else(answers::default_answers_path);
+    let mut store = match AnswerStore::load(&answers_path) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("Can't read answers file {}: {err}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut rows = vec![];
+    // Counts of parts, days which can't be checked are counted apart
+    let (mut passed, mut failed, mut missing, mut recorded) = (0, 0, 0, 0);
+    let (mut without_input, mut invalid_input) = (0, 0);
+    for solver in solvers {
+        let path = answers::input_path(solver.day(), &options.profile);
+        if !path.exists() {
+            eprintln!("Day {}: no input file {}", solver.day(), path.display());
+            without_input += 1;
+            continue;
+        }
+
+        let day_rows = match run_day(solver, &parts, Some(&path), config) {
+            Ok(day_rows) => day_rows,
+            Err(err) => {
+                eprintln!("{err}");
+                invalid_input += 1;
+                continue;
+            }
+        };
+
+        for mut row in day_rows {
+            let answer = row.answer.to_string();
+            let verdict = answers::check(&store, &options.profile, row.day, row.part, &answer);
+            let status = match verdict {
+                Verdict::Pass => {
+                    passed += 1;
+                    "PASS".to_string()
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    format!("FAIL (expected {expected})")
+                }
+                Verdict::Missing if options.record && row.answer != Answer::Unsolved => {
+                    store.record(&options.profile, row.day, row.part, answer);
+                    recorded += 1;
+                    "RECORDED".to_string()
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    "MISSING".to_string()
+                }
+            };
+
+            row.status = Some(status);
+            rows.push(row);
+        }
+    }
+
+    // Machine readable formats keep the summary out of the standard output
+    report::print_rows(&rows, options.format);
+    let summary =
+        format!("{passed} passed, {failed} failed, {missing} missing, {recorded} recorded");
+    match options.format {
+        Format::Table => println!("{summary}"),
+        _ => eprintln!("{summary}"),
+    }
+    if without_input > 0 {
+        eprintln!("{without_input} day(s) without input");
+    }
+    if invalid_input > 0 {
+        eprintln!("{invalid_input} day(s) with an invalid input");
+    }
+
+    if recorded > 0 {
+        if let Err(err) = store.save(&answers_path) {
+            eprintln!("Can't save answers to {}: {err}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match (failed, invalid_input) {
+        (0, 0) => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn fetch(day: Option<u8>, all: bool, session: &str, base_url: &str, delay_ms: u64) -> ExitCode {
+    let days: Vec<u8> = match (all, day) {
+        (true, _) => (1..=25).collect(),
+        (false, Some(day)) => vec![day],
+        (false, None) => unreachable!("clap requires a day when --all is not set"),
+    };
+
+    let mut fetcher = Fetcher::new(base_url, session, Duration::from_millis(delay_ms));
+    let mut failures = 0;
+    for day in days {
+        let path = input::default_input_path(day);
+        match fetcher.fetch(day, &path) {
+            Ok(Fetched::Cached) => println!("Day {day}: cached in {}", path.display()),
+            Ok(Fetched::Downloaded) => println!("Day {day}: downloaded to {}", path.display()),
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                failures += 1;
+            }
+        }
+    }
+
+    match failures {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn watch(
+    day: u8,
+    part: Option<u8>,
+    inputs: Vec<PathBuf>,
+    interval: Duration,
+    config_path: Option<&Path>,
+    overrides: &[String],
+) -> ExitCode {
+    let Some(solver) = aoc::solution(day) else {
+        eprintln!("Day {day} is not implemented");
+        return ExitCode::FAILURE;
+    };
+
+    let parts = parts_to_run(part);
+    let inputs = match inputs.is_empty() {
+        true => vec![input::default_input_path(day)],
+        false => inputs,
+    };
+    let mut input_files: Vec<WatchedFile> = inputs.into_iter().map(WatchedFile::new).collect();
+    let mut previous_runs: Vec<Option<Run>> = input_files.iter().map(|_| None).collect();
+    let mut config_file =
+        WatchedFile::new(config_path.map_or_else(config::default_config_path, Path::to_path_buf));
+    let mut params = None;
+
+    println!("Watching day {day}, Ctrl-C to stop");
+    loop {
+        // New parameters change the answers of every input
+        let config_changed = config_file.poll();
+        if config_changed {
+            params = match load_config(config_path, overrides)
+                .and_then(|config| day_params(solver, &config))
+            {
+                Ok(params) => Some(params),
+                Err(err) => {
+                    eprintln!("{err}");
+                    None
+                }
+            };
+        }
+
+        for (file, previous_run) in input_files.iter_mut().zip(&mut previous_runs) {
+            if !file.poll() && !config_changed {
+                continue;
+            }
+            let Some(params) = &params else {
+                continue;
+            };
+            let Some(raw_data) = file.content() else {
+                eprintln!("Day {day}: can't read input file {}", file.path().display());
+                continue;
+            };
+
+            println!("Day {day}, {}", file.path().display());
+            match watch::solve(solver, raw_data, &parts, params) {
+                Ok(run) => {
+                    for line in watch::changes(previous_run.as_ref(), &run) {
+                        println!("  {line}");
+                    }
+                    *previous_run = Some(run);
+                }
+                Err(err) => eprintln!("  {err}"),
+            }
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+struct VisualizeOptions {
+    animate: bool,
+    fps: u32,
+    every: usize,
+    #[cfg(feature = "png")]
+    output: Option<PathBuf>,
+    #[cfg(feature = "png")]
+    scale: u32,
+}
+
+#[cfg(feature = "png")]
+fn save_png_images(
+    visualization: &dyn Visualizer,
+    raw_data: &str,
+    params: &Params,
+    output: &Path,
+    options: &VisualizeOptions,
+) -> io::Result<usize> {
+    let name = format!("day{:02}", visualization.day());
+    let mut canvas = PngCanvas::new(output, &name, options.scale, options.every)?;
+    visualization.visualize(raw_data, params, &mut canvas)?;
+
+    Ok(canvas.written().len())
+}
+
+fn visualize(
+    day: u8,
+    input: Option<&Path>,
+    options: VisualizeOptions,
+    config: &Config,
+) -> ExitCode {
+    let Some(visualization) = aoc::visualization(day) else {
+        eprintln!("Day {day} has no visualization");
+        return ExitCode::FAILURE;
+    };
+
+    let params = match day_params(visualization, config) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let raw_data = match input::read_input(day, input) {
+        Ok(raw_data) => raw_data,
+        Err(err) => {
+            eprintln!("Day {day}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    #[cfg(feature = "png")]
+    if let Some(output) = &options.output {
+        return match save_png_images(visualization, &raw_data, &params, output, &options) {
+            Ok(image_count) => {
+                println!(
+                    "Day {day}: {image_count} images written to {}",
+                    output.display()
+                );
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut canvas = TerminalCanvas::new(
+        io::stdout().lock(),
+        options.animate,
+        options.fps,
+        options.every,
+    );
+    match visualization.visualize(&raw_data, &params, &mut canvas) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Day {day}: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn dump(day: u8, input: Option<&Path>, output: Option<&Path>, config: &Config) -> ExitCode {
+    let Some(dump) = aoc::dump(day) else {
+        eprintln!("Day {day} has no JSON dump");
+        return ExitCode::FAILURE;
+    };
+
+    let params = match day_params(dump, config) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let raw_data = match input::read_input(day, input) {
+        Ok(raw_data) => raw_data,
+        Err(err) => {
+            eprintln!("Day {day}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let model = match dump.dump(&raw_data, &params) {
+        Ok(model) => model,
+        Err(err) => {
+            eprintln!("Day {day}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let written = match output {
+        Some(path) => fs::File::create(path).and_then(|file| {
+            let mut writer = io::BufWriter::new(file);
+            serde_json::to_writer_pretty(&mut writer, &model)?;
+            writer.flush()
+        }),
+        None => {
+            let mut stdout = io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, &model)
+                .map_err(io::Error::from)
+                .and_then(|()| writeln!(stdout))
+        }
+    };
+
+    match written {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Day {day}: can't write the dump: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(
+    day: u8,
+    size: Option<usize>,
+    difficulty: Difficulty,
+    seed: u64,
+    config: &Config,
+) -> ExitCode {
+    let Some(generator) = aoc::generator(day) else {
+        eprintln!("Day {day} has no input generator");
+        return ExitCode::FAILURE;
+    };
+
+    // The input fits the parameters it will be solved with (size of the area...)
+    let params = match day_params(generator, config) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let size = size.unwrap_or(generator.default_size());
+    print!(
+        "{}",
+        generator.random_input(seed, size, difficulty, &params)
+    );
+
+    ExitCode::SUCCESS
+}
+
+fn init_tracing(verbose: u8, log: Option<&str>) -> Result<(), String> {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .parse(log.unwrap_or_default())
+        .map_err(|err| format!("Invalid log filter: {err}"))?;
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .init();
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(err) = init_tracing(cli.verbose, cli.log.as_deref()) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    let config = match load_config(cli.config.as_deref(), &cli.overrides) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Command::List => list(&config),
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            format,
+            jobs,
+        } => {
+            let jobs = match jobs {
+                Some(jobs) => jobs as usize,
+                None => thread::available_parallelism().map_or(1, |cpus| cpus.get()),
+            };
+            run(day, all, part, input, format, jobs, &config)
+        }
+        Command::Bench {
+            day,
+            all,
+            part,
+            input,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let options = BenchOptions {
+                iterations: iterations as usize,
+                save,
+                baseline,
+                threshold,
+            };
+            bench(day, all, part, input, options, &config)
+        }
+        Command::Verify {
+            day,
+            all,
+            part,
+            profile,
+            answers,
+            record,
+            format,
+        } => {
+            let options = VerifyOptions {
+                profile,
+                answers,
+                record,
+                format,
+            };
+            verify(day, all, part, options, &config)
+        }
+        Command::Watch {
+            day,
+            part,
+            input,
+            interval_ms,
+        } => watch(
+            day,
+            part,
+            input,
+            Duration::from_millis(interval_ms),
+            cli.config.as_deref(),
+            &cli.overrides,
+        ),
+        Command::Fetch {
+            day,
+            all,
+            session,
+            base_url,
+            delay_ms,
+        } => fetch(day, all, &session, &base_url, delay_ms),
+        Command::Visualize {
+            day,
+            input,
+            animate,
+            fps,
+            every,
+            #[cfg(feature = "png")]
+            output,
+            #[cfg(feature = "png")]
+            scale,
+        } => {
+            let options = VisualizeOptions {
+                animate,
+                fps,
+                every: every as usize,
+                #[cfg(feature = "png")]
+                output,
+                #[cfg(feature = "png")]
+                scale,
+            };
+            visualize(day, input.as_deref(), options, &config)
+        }
+        #[cfg(feature = "serde")]
+        Command::Dump { day, input, output } => {
+            dump(day, input.as_deref(), output.as_deref(), &config)
+        }
+        Command::Generate {
+            day,
+            size,
+            difficulty,
+            seed,
+        } => generate(day, size, difficulty, seed, &config),
+    }
+}
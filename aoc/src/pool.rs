@@ -0,0 +1,89 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    match payload.downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => "unknown panic".to_string(),
+    }
+}
+
+// Runs the jobs on a pool of worker threads, results are in the order of the jobs.
+// A panicking job gives the panic message, without stopping the other jobs
+pub fn run_jobs<J, T, F>(jobs: &[J], workers: usize, run_job: F) -> Vec<Result<T, String>>
+where
+    J: Sync,
+    T: Send,
+    F: Fn(&J) -> T + Sync,
+{
+    let next_job = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<T, String>>>> =
+        Mutex::new((0..jobs.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next_job.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(index) else {
+                    break;
+                };
+
+                let result = panic::catch_unwind(AssertUnwindSafe(|| run_job(job)))
+                    .map_err(|payload| panic_message(payload.as_ref()));
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("Every job is run"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panicking_jobs_do_not_stop_the_others() {
+        let results = run_jobs(&[1, 0, 2], 2, |value| match value {
+            0 => panic!("Job {value} panicked"),
+            _ => value * 10,
+        });
+
+        assert_eq!(
+            results,
+            vec![Ok(10), Err("Job 0 panicked".to_string()), Ok(20)]
+        );
+    }
+
+    #[test]
+    fn results_are_in_the_order_of_the_jobs() {
+        let jobs: Vec<u64> = (0..100).collect();
+        for workers in [0, 1, 3, 200] {
+            let results = run_jobs(&jobs, workers, |value| value * value);
+            let expected: Vec<Result<u64, String>> =
+                jobs.iter().map(|value| Ok(value * value)).collect();
+            assert_eq!(results, expected);
+        }
+
+        assert!(run_jobs(&[] as &[u64], 4, |value| *value).is_empty());
+    }
+
+    #[test]
+    fn panic_messages() {
+        assert_eq!(panic_message(&"static message"), "static message");
+        assert_eq!(panic_message(&"formatted".to_string()), "formatted");
+        assert_eq!(panic_message(&42), "unknown panic");
+    }
+}
@@ -0,0 +1,181 @@
+use aoc_core::Answer;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+pub struct Row {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub input_hash: String,
+    pub status: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Aligned columns, for humans
+    #[default]
+    Table,
+    /// One JSON array of results
+    Json,
+    /// Tab separated values, with a header line
+    Tsv,
+}
+
+// SHA-256 of the puzzle input, in hexadecimal
+pub fn input_hash(raw_data: &str) -> String {
+    Sha256::digest(raw_data.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    match micros {
+        _ if micros < 1.0 => format!("{}ns", duration.as_nanos()),
+        _ if micros < 1_000.0 => format!("{micros:.1}µs"),
+        _ if micros < 1_000_000.0 => format!("{:.1}ms", micros / 1_000.0),
+        _ => format!("{:.2}s", micros / 1_000_000.0),
+    }
+}
+
+pub fn print_table(rows: &[Row]) {
+    let answers: Vec<String> = rows.iter().map(|row| row.answer.to_string()).collect();
+    let title_width = rows
+        .iter()
+        .map(|row| row.title.chars().count())
+        .chain(["Title".len()])
+        .max()
+        .unwrap();
+    let answer_width = answers
+        .iter()
+        .map(|answer| answer.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+
+    // The status column is only shown when rows have one
+    let with_status = rows.iter().any(|row| row.status.is_some());
+
+    print!(
+        "{:>3}  {:<title_width$}  {:>4}  {:<answer_width$}  {:>9}",
+        "Day", "Title", "Part", "Answer", "Time"
+    );
+    match with_status {
+        true => println!("  Status"),
+        false => println!(),
+    }
+
+    for (row, answer) in rows.iter().zip(answers) {
+        print!(
+            "{:>3}  {:<title_width$}  {:>4}  {:<answer_width$}  {:>9}",
+            row.day,
+            row.title,
+            row.part,
+            answer,
+            format_duration(row.elapsed)
+        );
+        match (with_status, &row.status) {
+            (true, Some(status)) => println!("  {status}"),
+            _ => println!(),
+        }
+    }
+}
+
+fn json_answer(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(value) => json!(value),
+        Answer::Text(text) => json!(text),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRow<'a> {
+    day: u8,
+    title: &'a str,
+    part: u8,
+    answer: Value,
+    duration_ns: u64,
+    input_hash: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'a str>,
+}
+
+pub fn print_json(rows: &[Row]) {
+    let results: Vec<JsonRow> = rows
+        .iter()
+        .map(|row| JsonRow {
+            day: row.day,
+            title: row.title,
+            part: row.part,
+            answer: json_answer(&row.answer),
+            duration_ns: row.elapsed.as_nanos() as u64,
+            input_hash: &row.input_hash,
+            status: row.status.as_deref(),
+        })
+        .collect();
+
+    println!("{}", serde_json::to_string_pretty(&results).unwrap());
+}
+
+// Text fields must stay on one line and in one column (parse errors span several lines)
+fn tsv_field(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+pub fn print_tsv(rows: &[Row]) {
+    let with_status = rows.iter().any(|row| row.status.is_some());
+
+    print!("day\ttitle\tpart\tanswer\tduration_ns\tinput_hash");
+    match with_status {
+        true => println!("\tstatus"),
+        false => println!(),
+    }
+
+    for row in rows {
+        print!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            row.day,
+            tsv_field(row.title),
+            row.part,
+            tsv_field(&row.answer.to_string()),
+            row.elapsed.as_nanos(),
+            row.input_hash
+        );
+        match with_status {
+            true => println!("\t{}", tsv_field(row.status.as_deref().unwrap_or_default())),
+            false => println!(),
+        }
+    }
+}
+
+pub fn print_rows(rows: &[Row], format: Format) {
+    match format {
+        Format::Table => print_table(rows),
+        Format::Json => print_json(rows),
+        Format::Tsv => print_tsv(rows),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tsv_fields_are_escaped() {
+        assert_eq!(tsv_field("6,1"), "6,1");
+        assert_eq!(
+            tsv_field("line 2\tcolumn 3:\r\n\\found"),
+            "line 2\\tcolumn 3:\\r\\n\\\\found"
+        );
+    }
+}
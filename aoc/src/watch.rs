@@ -0,0 +1,160 @@
+use crate::pool;
+use crate::report::format_duration;
+use aoc_core::{Answer, Params, Run, Solver};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// File polled for changes: its content is compared to the one seen last time (a file which
+// can't be read, while it is being saved or when it does not exist yet, has no content)
+pub struct WatchedFile {
+    path: PathBuf,
+    content: Option<String>,
+    polled: bool,
+}
+
+impl WatchedFile {
+    pub fn new(path: PathBuf) -> WatchedFile {
+        WatchedFile {
+            path,
+            content: None,
+            polled: false,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    // True when the content changed since the last poll (always true for the first one)
+    pub fn poll(&mut self) -> bool {
+        let content = fs::read_to_string(&self.path).ok();
+        let changed = !self.polled || content != self.content;
+
+        self.content = content;
+        self.polled = true;
+        changed
+    }
+}
+
+// Panics are reported like parse errors: a half edited input must not stop the watch
+pub fn solve(
+    solver: &dyn Solver,
+    raw_data: &str,
+    parts: &[u8],
+    params: &Params,
+) -> Result<Run, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| solver.run(raw_data, parts, params))) {
+        Ok(Ok(run)) => Ok(run),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(payload) => Err(format!("PANIC: {}", pool::panic_message(payload.as_ref()))),
+    }
+}
+
+fn format_change(elapsed: Duration, previous: Duration) -> String {
+    match elapsed >= previous {
+        true => format!("+{}", format_duration(elapsed - previous)),
+        false => format!("-{}", format_duration(previous - elapsed)),
+    }
+}
+
+fn change_line(
+    label: &str,
+    answer: Option<&Answer>,
+    elapsed: Duration,
+    previous: Option<(Option<&Answer>, Duration)>,
+) -> String {
+    let mut line = format!("{label:<7}");
+    if let Some(answer) = answer {
+        line += &format!(" {answer}");
+        match previous {
+            Some((Some(previous_answer), _)) if previous_answer != answer => {
+                line += &format!(" (was {previous_answer})");
+            }
+            Some(_) => line += " (same)",
+            None => {}
+        }
+    }
+
+    line += &format!("  {}", format_duration(elapsed));
+    if let Some((_, previous_elapsed)) = previous {
+        line += &format!(" ({})", format_change(elapsed, previous_elapsed));
+    }
+
+    line
+}
+
+// Answers and times of a run, next to the ones of the previous run of the same input
+pub fn changes(previous: Option<&Run>, run: &Run) -> Vec<String> {
+    let mut lines = vec![change_line(
+        "Parse",
+        None,
+        run.parse_time,
+        previous.map(|previous| (None, previous.parse_time)),
+    )];
+
+    for part_run in &run.parts {
+        let previous_part = previous
+            .and_then(|previous| {
+                previous
+                    .parts
+                    .iter()
+                    .find(|previous_part| previous_part.part == part_run.part)
+            })
+            .map(|previous_part| (Some(&previous_part.answer), previous_part.elapsed));
+
+        lines.push(change_line(
+            &format!("Part {}", part_run.part),
+            Some(&part_run.answer),
+            part_run.elapsed,
+            previous_part,
+        ));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::PartRun;
+
+    fn run(answers: &[Answer], millis: u64) -> Run {
+        Run {
+            parse_time: Duration::from_millis(millis),
+            parts: answers
+                .iter()
+                .enumerate()
+                .map(|(index, answer)| PartRun {
+                    part: index as u8 + 1,
+                    answer: answer.clone(),
+                    elapsed: Duration::from_millis(millis),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn changes_show_the_previous_answers_and_times() {
+        let first = run(&[Answer::from(22), Answer::from("6,1")], 3);
+        assert_eq!(
+            changes(None, &first),
+            ["Parse    3.0ms", "Part 1  22  3.0ms", "Part 2  6,1  3.0ms"]
+        );
+
+        let second = run(&[Answer::from(24), Answer::from("6,1")], 2);
+        assert_eq!(
+            changes(Some(&first), &second),
+            [
+                "Parse    2.0ms (-1.0ms)",
+                "Part 1  24 (was 22)  2.0ms (-1.0ms)",
+                "Part 2  6,1 (same)  2.0ms (-1.0ms)"
+            ]
+        );
+    }
+}
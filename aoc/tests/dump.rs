@@ -0,0 +1,66 @@
+#![cfg(feature = "serde")]
+
+use serde_json::Value;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const DAY13_EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+";
+
+const DAY17_EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+fn dump(day: &str, raw_data: &str) -> Value {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["dump", day, "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(raw_data.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn dumped_models_can_be_read_back() {
+    let machines: Vec<day13::ClawMachine> =
+        serde_json::from_value(dump("13", DAY13_EXAMPLE)).unwrap();
+    assert_eq!(machines.len(), 2);
+    assert_eq!(machines[1].prize.x, 12748);
+    assert_eq!(machines[0].minimal_cost_optimized(0), Some(280));
+
+    let mut program: day17::Program = serde_json::from_value(dump("17", DAY17_EXAMPLE)).unwrap();
+    assert_eq!(program.register().a, 729);
+    assert_eq!(program.execute().as_deref(), Some("4,6,3,5,6,3,5,2,1,0"));
+}
+
+#[test]
+fn days_without_dump_are_rejected() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["dump", "1", "--input", "-"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no JSON dump"));
+}
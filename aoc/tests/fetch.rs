@@ -0,0 +1,109 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::{env, fs, thread};
+
+const SESSION: &str = "stub-session";
+
+// Minimal HTTP server: answers the input of any day to requests with the right session cookie
+fn start_stub_server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/2024", listener.local_addr().unwrap());
+    let request_count = Arc::new(AtomicUsize::new(0));
+
+    let counter = request_count.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            let mut request_lines = vec![];
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                request_lines.push(line.trim_end().to_string());
+            }
+
+            let path = request_lines[0].split(' ').nth(1).unwrap_or_default();
+            let authorized = request_lines
+                .iter()
+                .any(|line| line.eq_ignore_ascii_case(&format!("cookie: session={SESSION}")));
+            let day = path
+                .strip_prefix("/2024/day/")
+                .and_then(|rest| rest.strip_suffix("/input"));
+
+            let (status, body) = match (authorized, day) {
+                (false, _) => (
+                    "400 Bad Request",
+                    "Puzzle inputs differ by user.\n".to_string(),
+                ),
+                (true, Some(day)) => ("200 OK", format!("input of day {day}\n")),
+                (true, None) => ("404 Not Found", "Not found\n".to_string()),
+            };
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, request_count)
+}
+
+fn inputs_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn fetch(inputs_dir: &Path, base_url: &str, session: &str, day: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", day, "--delay-ms", "0"])
+        .env("AOC_INPUTS", inputs_dir)
+        .env("AOC_BASE_URL", base_url)
+        .env("AOC_SESSION", session)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn downloads_once_then_uses_the_cache() {
+    let (base_url, request_count) = start_stub_server();
+    let inputs_dir = inputs_dir("cache");
+
+    let output = fetch(&inputs_dir, &base_url, SESSION, "3");
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(inputs_dir.join("day03.txt")).unwrap(),
+        "input of day 3\n"
+    );
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+    let output = fetch(&inputs_dir, &base_url, SESSION, "3");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("cached"));
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+    fs::remove_dir_all(&inputs_dir).unwrap();
+}
+
+#[test]
+fn invalid_session_writes_nothing() {
+    let (base_url, _) = start_stub_server();
+    let inputs_dir = inputs_dir("session");
+
+    let output = fetch(&inputs_dir, &base_url, "wrong-session", "5");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("session"));
+    assert!(!inputs_dir.join("day05.txt").exists());
+}
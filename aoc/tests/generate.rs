@@ -0,0 +1,38 @@
+use aoc_core::{Difficulty, Params};
+
+const SEEDS: u64 = 3;
+
+// Small random inputs of every day must be parsed and solved without panicking
+#[test]
+fn random_inputs_are_solved() {
+    for generator in aoc::generators() {
+        let size = (generator.default_size() / 10).max(1);
+        let params = Params::defaults(generator.params());
+        for difficulty in Difficulty::ALL {
+            for seed in 0..SEEDS {
+                let raw_data = generator.random_input(seed, size, difficulty, &params);
+                if let Err(err) = generator.run(&raw_data, &[1, 2], &params) {
+                    panic!("Day {} ({difficulty}, seed {seed}): {err}", generator.day());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn random_inputs_only_depend_on_the_seed() {
+    for generator in aoc::generators() {
+        let size = generator.default_size();
+        let params = Params::defaults(generator.params());
+        let input = generator.random_input(1, size, Difficulty::Normal, &params);
+
+        assert_eq!(
+            input,
+            generator.random_input(1, size, Difficulty::Normal, &params)
+        );
+        assert_ne!(
+            input,
+            generator.random_input(2, size, Difficulty::Normal, &params)
+        );
+    }
+}
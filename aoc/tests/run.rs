@@ -0,0 +1,138 @@
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::Command;
+use std::{env, fs};
+
+const DAY1_EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+const DAY18_EXAMPLE: &str = "\
+5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n\
+6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0\n";
+
+// Combo operand 7 is reserved: this program is rejected by the parser
+const DAY17_INVALID_PROGRAM: &str = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,7\n";
+
+fn inputs_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-run-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn failing_days_do_not_stop_the_others() {
+    let inputs_dir = inputs_dir("all");
+    fs::write(inputs_dir.join("day01.txt"), DAY1_EXAMPLE).unwrap();
+    fs::write(inputs_dir.join("day17.txt"), DAY17_INVALID_PROGRAM).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--all", "--jobs", "4", "--format", "json"])
+        .env("AOC_INPUTS", &inputs_dir)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let rows: Vec<Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(rows.len(), 2 * aoc::solutions().len());
+
+    let row = |day: u64, part: u64| {
+        rows.iter()
+            .find(|row| row["day"] == day && row["part"] == part)
+            .unwrap()
+    };
+    assert_eq!(row(1, 1)["answer"], 11);
+    assert_eq!(row(1, 2)["answer"], 31);
+    assert!(row(1, 1).get("status").is_none());
+    assert!(row(17, 1)["status"].as_str().unwrap().contains("operand"));
+    assert!(row(2, 1)["status"].as_str().unwrap().starts_with("ERROR"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("21 day(s), 20 failed"));
+
+    fs::remove_dir_all(&inputs_dir).unwrap();
+}
+
+fn run_day18_example(args: &[&str]) -> std::process::Output {
+    let inputs_dir = inputs_dir("day18");
+    let input = inputs_dir.join("example.txt");
+    fs::write(&input, DAY18_EXAMPLE).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "18", "--format", "json", "--input"])
+        .arg(&input)
+        .args(args)
+        .env_remove("AOC_CONFIG")
+        .output()
+        .unwrap();
+
+    fs::remove_dir_all(&inputs_dir).unwrap();
+    output
+}
+
+#[test]
+fn examples_run_with_their_parameters() {
+    let examples_config = concat!(env!("CARGO_MANIFEST_DIR"), "/../examples.toml");
+    let overrides = [
+        "--set",
+        "day18.width=7",
+        "--set",
+        "day18.height=7",
+        "--set",
+        "day18.fallen_bytes=12",
+        "--set",
+        "day18.first_checked_byte=12",
+    ];
+
+    for args in [&["--config", examples_config][..], &overrides] {
+        let output = run_day18_example(args);
+        assert!(output.status.success());
+
+        let rows: Vec<Value> = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(rows[0]["answer"], 22);
+        assert_eq!(rows[1]["answer"], "6,1");
+    }
+}
+
+#[test]
+fn invalid_parameters_are_reported() {
+    for (args, error) in [
+        (["--set", "day18.widht=7"], "unknown parameter `widht`"),
+        (["--set", "day18.width=0"], "must be between 1 and 1000"),
+        (["--set", "width=7"], "expected dayN.name=value"),
+        (["--config", "missing.toml"], "Can't read config file"),
+    ] {
+        // Day errors are in the status column, configuration errors on stderr
+        let output = run_day18_example(&args);
+        assert!(!output.status.success());
+        let messages = [output.stdout, output.stderr].concat();
+        assert!(String::from_utf8_lossy(&messages).contains(error));
+    }
+}
+
+#[test]
+fn verify_counts_parts_and_reports_invalid_inputs_apart() {
+    let inputs_dir = inputs_dir("verify");
+    fs::write(inputs_dir.join("day01.txt"), DAY1_EXAMPLE).unwrap();
+    fs::write(inputs_dir.join("day17.txt"), DAY17_INVALID_PROGRAM).unwrap();
+    fs::write(
+        inputs_dir.join("answers.json"),
+        r#"{"default": {"1": {"part1": "11", "part2": "30"}}}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", "--all"])
+        .env("AOC_INPUTS", &inputs_dir)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("FAIL (expected 30)"));
+    assert!(stdout.contains("1 passed, 1 failed, 0 missing, 0 recorded"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("1 day(s) with an invalid input"));
+    assert!(stderr.contains("19 day(s) without input"));
+
+    fs::remove_dir_all(&inputs_dir).unwrap();
+}
@@ -0,0 +1,53 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{env, fs, thread};
+
+const DAY1_EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+const DAY1_EDITED_EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   7\n";
+
+#[test]
+fn edited_inputs_are_solved_again() {
+    let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("example.txt");
+    fs::write(&input, DAY1_EXAMPLE).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["watch", "1", "--interval-ms", "20", "--input"])
+        .arg(&input)
+        .env_remove("AOC_CONFIG")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // Lines are read by another thread, so that a broken watch fails instead of blocking
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if sender.send(line.unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+    let wait_for = |expected: &str| loop {
+        let line = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        if line.trim_start().starts_with(expected) {
+            break line;
+        }
+    };
+
+    assert!(wait_for("Part 1").contains(" 11 "));
+    assert!(wait_for("Part 2").contains(" 31 "));
+
+    fs::write(&input, DAY1_EDITED_EXAMPLE).unwrap();
+    assert!(wait_for("Part 1").contains(" 15 (was 11) "));
+    assert!(wait_for("Part 2").contains(" 22 (was 31) "));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,121 @@
+//! Day 1: Historian Hysteria, distance and similarity between two lists of location IDs
+
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+
+/// The two lists of location IDs, each one sorted
+pub struct Locations {
+    left: Vec<i32>,
+    right: Vec<i32>,
+}
+
+impl Locations {
+    /// Lists of the same length, in any order
+    pub fn new(mut left: Vec<i32>, mut right: Vec<i32>) -> Self {
+        left.sort();
+        right.sort();
+        Locations { left, right }
+    }
+
+    pub fn left(&self) -> &[i32] {
+        &self.left
+    }
+
+    pub fn right(&self) -> &[i32] {
+        &self.right
+    }
+
+    /// Sum of the distances between the IDs of both lists, smallest ones first, if it fits
+    pub fn distance(&self) -> Option<u64> {
+        self.left
+            .iter()
+            .zip(self.right.iter())
+            .map(|(ls, rs)| u64::from(ls.abs_diff(*rs)))
+            .try_fold(0_u64, u64::checked_add)
+    }
+
+    /// Sum of the left IDs, each one multiplied by its count in the right list
+    pub fn similarity(&self) -> usize {
+        self.left
+            .iter()
+            .map(|ls| (*ls as usize) * self.right.iter().filter(|&rs| rs == ls).count())
+            .sum()
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Locations;
+
+    fn parse(raw_data: &str) -> Result<Locations, ParseError> {
+        let separator = "   ";
+
+        let pairs = Parser::new(raw_data).parse_lines(|line| {
+            let left = line.unsigned::<i32>()?;
+            line.literal(separator)?;
+            Ok((left, line.unsigned::<i32>()?))
+        })?;
+
+        let (left, right) = pairs.into_iter().unzip();
+        Ok(Locations::new(left, right))
+    }
+
+    fn part1(locations: &Locations) -> Answer {
+        locations.distance().into()
+    }
+
+    fn part2(locations: &Locations) -> Answer {
+        locations.similarity().into()
+    }
+}
+
+impl Generate for Day1 {
+    // Lines of the two lists
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // Harder lists share more location ids, which matters for the similarity
+        let ids_count = (size / difficulty.pick(1, 2, 10)).max(1);
+        let ids: Vec<i64> = (0..ids_count).map(|_| rng.between(10000, 99999)).collect();
+
+        (0..size)
+            .map(|_| format!("{}   {}\n", rng.pick(&ids), rng.pick(&ids)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), Answer::from(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), Answer::from(31));
+    }
+
+    #[test]
+    fn large_ids() {
+        let input = Day1::parse("2147483647   0\n2147483647   0\n").unwrap();
+        assert_eq!(Day1::part1(&input), Answer::from(4294967294_u64));
+        assert_eq!(Day1::part2(&input), Answer::from(0));
+    }
+}
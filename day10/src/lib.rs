@@ -0,0 +1,167 @@
+//! Day 10: Hoof It, hiking trails climbing from height 0 to height 9
+
+use aoc_core::{Answer, Difficulty, Generate, Grid, ParseError, Rng, Solution, Vector2d};
+use std::collections::HashSet;
+
+/// Digit from 0 to 9
+pub type Height = usize;
+
+/// Heights of the area, and the trailheads (height 0)
+pub struct TopographicMap {
+    heights: Grid<Height>,
+    trailheads: Vec<Vector2d>,
+}
+
+impl TopographicMap {
+    pub fn heights(&self) -> &Grid<Height> {
+        &self.heights
+    }
+
+    pub fn trailheads(&self) -> &[Vector2d] {
+        &self.trailheads
+    }
+
+    /// Sums over the trailheads of the reachable tops (score) and of the distinct trails (rating)
+    pub fn get_score_and_rating(&self) -> (usize, usize) {
+        self.trailheads
+            .iter()
+            .map(|trailhead| self.get_score_for(trailhead))
+            .fold((0, 0), |acc, (score, rating)| {
+                (acc.0 + score, acc.1 + rating)
+            })
+    }
+
+    /// Score and rating of a single trailhead
+    pub fn get_score_for(&self, trailhead: &Vector2d) -> (usize, usize) {
+        let mut reachable_tops: HashSet<Vector2d> = HashSet::new();
+        let mut rating = 0;
+        // Check 4 directions
+        self.reach_top_rec(trailhead, 0, &mut reachable_tops, &mut rating);
+
+        (reachable_tops.len(), rating)
+    }
+
+    fn reach_top_rec(
+        &self,
+        current_pos: &Vector2d,
+        current_height: Height,
+        tops: &mut HashSet<Vector2d>,
+        rating: &mut usize,
+    ) {
+        if 9 == current_height {
+            *rating += 1;
+            tops.insert(*current_pos);
+            return;
+        }
+
+        for next_pos in self.heights.neighbours4(current_pos) {
+            if self.heights[next_pos] == current_height + 1 {
+                self.reach_top_rec(&next_pos, current_height + 1, tops, rating);
+            }
+        }
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<TopographicMap, ParseError> {
+    let heights = Grid::try_parse(
+        raw_data,
+        |c| c.to_digit(10).map(|height| height as usize),
+        "a height digit",
+    )?;
+    let trailheads = heights.find_all(|height| 0 == *height).collect();
+
+    Ok(TopographicMap {
+        heights,
+        trailheads,
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = TopographicMap;
+
+    fn parse(raw_data: &str) -> Result<TopographicMap, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(map: &TopographicMap) -> Answer {
+        let (score, _) = map.get_score_and_rating();
+        score.into()
+    }
+
+    fn part2(map: &TopographicMap) -> Answer {
+        let (_, rating) = map.get_score_and_rating();
+        rating.into()
+    }
+}
+
+impl Generate for Day10 {
+    // Side of the topographic map
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let side = size.max(1);
+        let mut heights = Grid::new(side, side, 0);
+        for pos in heights.positions().collect::<Vec<_>>() {
+            heights.set(&pos, rng.below(10));
+        }
+
+        // Hiking trails are random walks climbing from 0 to 9, more trails cross each other
+        let trails_count = side * side / difficulty.pick(50, 20, 8);
+        for _ in 0..trails_count {
+            let mut trail = vec![Vector2d::new(
+                rng.below(side) as i64,
+                rng.below(side) as i64,
+            )];
+            for height in 1..=9 {
+                let next_positions: Vec<Vector2d> = heights
+                    .neighbours4(&trail[height - 1])
+                    .filter(|next_pos| !trail.contains(next_pos))
+                    .collect();
+                if next_positions.is_empty() {
+                    break;
+                }
+
+                trail.push(*rng.pick(&next_positions));
+            }
+
+            for (height, pos) in trail.iter().enumerate() {
+                heights.set(pos, height);
+            }
+        }
+
+        heights.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), Answer::from(36));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), Answer::from(81));
+    }
+}
@@ -1,29 +0,0 @@
-use std::ops;
-
-#[derive(Clone, Copy, Hash, Eq, PartialEq)]
-pub struct Vector2d {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl ops::Add<Vector2d> for Vector2d {
-    type Output = Vector2d;
-
-    fn add(self, dir: Vector2d) -> Vector2d {
-        Vector2d {
-            x: self.x + dir.x,
-            y: self.y + dir.y,
-        }
-    }
-}
-
-impl ops::Sub<Vector2d> for Vector2d {
-    type Output = Vector2d;
-
-    fn sub(self, other: Vector2d) -> Vector2d {
-        Vector2d {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
-    }
-}
@@ -0,0 +1,243 @@
+//! Day 11: Plutonian Pebbles, stones changing every time you blink
+
+use aoc_core::{Answer, Difficulty, Generate, Param, Params, ParseError, Parser, Rng, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Number engraved on a stone
+pub type Stone = usize;
+
+fn get_digit_count(value: usize) -> usize {
+    value.ilog10() as usize + 1
+}
+
+/// Stones replacing a stone after a blink: 0 becomes 1, an even number of digits is split
+/// in two stones, any other stone is multiplied by 2024
+pub fn blink(stone: Stone) -> Vec<Stone> {
+    if 0 == stone {
+        return vec![1];
+    }
+
+    let digit_count = get_digit_count(stone);
+    if digit_count.is_multiple_of(2) {
+        let power_of_10 = 10_usize.pow(digit_count as u32 / 2);
+        let lvalue = stone / power_of_10;
+        let rvalue = stone - lvalue * power_of_10;
+
+        return vec![lvalue, rvalue];
+    }
+
+    vec![stone * 2024]
+}
+
+/// Count of stones after blinking, keeping every stone in order (slow past 25 blinks)
+pub fn get_stones_count(stones: &[Stone], blink_count: usize) -> usize {
+    (0..blink_count)
+        .fold(stones.to_vec(), |acc, _| {
+            let mut next_stones = vec![];
+            for stone in acc {
+                let mut next = blink(stone);
+                next_stones.append(&mut next);
+            }
+
+            next_stones
+        })
+        .len()
+}
+
+/// Stones counted by number: their order never changes the count
+pub struct Stones {
+    unordered_stones: HashMap<Stone, usize>,
+}
+
+impl Stones {
+    pub fn new(stones: &Vec<Stone>) -> Stones {
+        let mut unordered_stones = HashMap::new();
+        for stone in stones {
+            *unordered_stones.entry(*stone).or_default() += 1_usize;
+        }
+
+        Stones { unordered_stones }
+    }
+
+    fn add_stones(stone: Stone, count: usize, unordered_stones: &mut HashMap<Stone, usize>) {
+        *unordered_stones.entry(stone).or_default() += count;
+    }
+
+    pub fn blink(&mut self) {
+        // Reduce initial (not optimized) vector size by using a hashmap (to group stones with same values)
+        let mut next_stones = HashMap::new();
+        for (stone, count) in &self.unordered_stones {
+            if 0 == *stone {
+                Self::add_stones(1, *count, &mut next_stones);
+                continue;
+            }
+
+            let digit_count = get_digit_count(*stone);
+            if digit_count.is_multiple_of(2) {
+                let power_of_10 = 10_usize.pow(digit_count as u32 / 2);
+                let lvalue = stone / power_of_10;
+                let rvalue = stone - lvalue * power_of_10;
+
+                Self::add_stones(lvalue, *count, &mut next_stones);
+                Self::add_stones(rvalue, *count, &mut next_stones);
+                continue;
+            }
+
+            Self::add_stones(stone * 2024, *count, &mut next_stones);
+        }
+
+        self.unordered_stones = next_stones;
+    }
+
+    /// Count of stones after blinking
+    pub fn get_stones_count(&mut self, blink_count: usize) -> usize {
+        for _ in 0..blink_count {
+            self.blink();
+        }
+
+        self.unordered_stones.values().sum()
+    }
+}
+
+impl fmt::Display for Stones {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (stone, count) in &self.unordered_stones {
+            writeln!(f, "{stone} [{count}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Stones with an odd number of digits are multiplied at most twice in a row before being split:
+// up to 12 digits, they never overflow
+const MAX_STONE: Stone = 999_999_999_999;
+
+fn parse_stone(parser: &mut Parser) -> Result<Stone, ParseError> {
+    let start = *parser;
+    let stone = parser.unsigned()?;
+    match stone <= MAX_STONE {
+        true => Ok(stone),
+        false => Err(start.error(format!("a stone up to {MAX_STONE}"))),
+    }
+}
+
+/// Blinks of each part (puzzle parameters)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BlinkParams {
+    pub part1_blinks: usize,
+    pub part2_blinks: usize,
+}
+
+impl BlinkParams {
+    fn new(params: &Params) -> BlinkParams {
+        BlinkParams {
+            part1_blinks: params.get("part1_blinks"),
+            part2_blinks: params.get("part2_blinks"),
+        }
+    }
+}
+
+/// Stones in a line, in front of you
+pub struct Arrangement {
+    pub stones: Vec<Stone>,
+    pub params: BlinkParams,
+}
+
+fn parse_input(raw_data: &str) -> Result<Vec<Stone>, ParseError> {
+    let mut parser = Parser::new(raw_data.trim_end());
+    let stones = parser.separated(" ", parse_stone)?;
+    parser.end()?;
+
+    Ok(stones)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    // The naive count of part 1 keeps every stone in memory
+    const PARAMS: &'static [Param] = &[
+        Param::new("part1_blinks", 25, 0..=35),
+        Param::new("part2_blinks", 75, 0..=90),
+    ];
+
+    type Input = Arrangement;
+
+    fn parse(raw_data: &str) -> Result<Arrangement, ParseError> {
+        Self::parse_with(raw_data, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(raw_data: &str, params: &Params) -> Result<Arrangement, ParseError> {
+        Ok(Arrangement {
+            stones: parse_input(raw_data)?,
+            params: BlinkParams::new(params),
+        })
+    }
+
+    fn part1(arrangement: &Arrangement) -> Answer {
+        get_stones_count(&arrangement.stones, arrangement.params.part1_blinks).into()
+    }
+
+    fn part2(arrangement: &Arrangement) -> Answer {
+        let mut opt_stones = Stones::new(&arrangement.stones);
+        opt_stones
+            .get_stones_count(arrangement.params.part2_blinks)
+            .into()
+    }
+}
+
+impl Generate for Day11 {
+    // Stones
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let value_max = difficulty.pick(100, 1_000_000, 1_000_000_000);
+
+        let stones: Vec<String> = (0..size.max(1))
+            .map(|_| rng.between(0, value_max).to_string())
+            .collect();
+        stones.join(" ") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+125 17
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), Answer::from(55312));
+    }
+
+    #[test]
+    fn duplicated_stones_are_counted() {
+        let stones = vec![0, 0, 17];
+        assert_eq!(Stones::new(&stones).get_stones_count(0), 3);
+        assert_eq!(
+            Stones::new(&stones).get_stones_count(6),
+            get_stones_count(&stones, 6)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn grouped_stones_match_the_naive_simulation(
+            // Small values make duplicated stones likely
+            stones in prop::collection::vec(prop_oneof![0..10_usize, 0..1_000_000_usize], 1..8),
+            blink_count in 0..16_usize,
+        ) {
+            let expected = get_stones_count(&stones, blink_count);
+            prop_assert_eq!(Stones::new(&stones).get_stones_count(blink_count), expected);
+        }
+    }
+}
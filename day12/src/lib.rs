@@ -0,0 +1,492 @@
+//! Day 12: Garden Groups, fencing prices of the garden regions
+
+use aoc_core::{
+    render, Answer, Canvas, Difficulty, Direction, Generate, Grid, ParseError, Rgb, Rng, Solution,
+    Vector2d, Visualize,
+};
+use std::collections::{HashMap, HashSet};
+use std::io;
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq)]
+struct Fence {
+    pos1: Vector2d,
+    pos2: Vector2d,
+}
+
+impl Fence {
+    fn dir(&self) -> Vector2d {
+        self.pos1 - self.pos2
+    }
+
+    fn try_merge(&mut self, fence: &Fence) -> Option<Fence> {
+        if self.pos1 == fence.pos1 {
+            return Some(Fence {
+                pos1: self.pos2,
+                pos2: fence.pos2,
+            });
+        }
+        if self.pos1 == fence.pos2 {
+            return Some(Fence {
+                pos1: self.pos2,
+                pos2: fence.pos1,
+            });
+        }
+        if self.pos2 == fence.pos1 {
+            return Some(Fence {
+                pos1: self.pos1,
+                pos2: fence.pos2,
+            });
+        }
+        if self.pos2 == fence.pos2 {
+            return Some(Fence {
+                pos1: self.pos1,
+                pos2: fence.pos1,
+            });
+        }
+
+        None
+    }
+}
+
+/// Letter of the plant type
+pub type Plant = char;
+
+/// Contiguous plots growing the same plant
+pub struct Region {
+    plant: Plant,
+    positions: HashSet<Vector2d>,
+    perimeter: usize,
+    unit_fences: Vec<Fence>,
+}
+
+impl Region {
+    fn new(plant: Plant) -> Region {
+        Region {
+            plant,
+            perimeter: 0,
+            unit_fences: vec![],
+            positions: HashSet::new(),
+        }
+    }
+
+    pub fn plant(&self) -> Plant {
+        self.plant
+    }
+
+    pub fn positions(&self) -> &HashSet<Vector2d> {
+        &self.positions
+    }
+
+    pub fn area(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// Straight sections of fence (each side counts once, whatever its length)
+    pub fn sides(&self) -> usize {
+        self.fences()
+    }
+
+    pub fn price(&self) -> Price {
+        let area = self.positions.len();
+
+        Price {
+            with_perimeter: area * self.perimeter,
+            with_fences: area * self.fences(),
+        }
+    }
+
+    fn count_fence_pos(&self, pos: &Vector2d) -> usize {
+        let mut counter = 0;
+        for fence in &self.unit_fences {
+            if fence.pos1 == *pos {
+                counter += 1;
+            }
+            if fence.pos2 == *pos {
+                counter += 1;
+            }
+        }
+
+        counter
+    }
+
+    fn fences(&self) -> usize {
+        let mut processed: HashSet<Fence> = HashSet::new();
+        let mut fences_count = 0;
+        let mut merged_fences: Vec<Fence> = vec![];
+
+        for fence in &self.unit_fences {
+            if processed.contains(fence) {
+                continue;
+            }
+
+            fences_count += 1;
+
+            let mut current_fence = *fence;
+
+            processed.insert(*fence);
+
+            loop {
+                let mut merge_count = 0;
+
+                // Search for fences which can be "merged" together
+                for other_unit_fence in &self.unit_fences {
+                    if processed.contains(other_unit_fence) {
+                        continue;
+                    }
+
+                    let dir1 = fence.dir();
+                    let dir2 = other_unit_fence.dir();
+                    if 0 == dir1.dot_product(&dir2) {
+                        continue;
+                    }
+
+                    if let Some(merged_fence) = current_fence.try_merge(other_unit_fence) {
+                        current_fence = merged_fence;
+                        merge_count += 1;
+                        processed.insert(*other_unit_fence);
+                    }
+                }
+
+                if merge_count == 0 {
+                    merged_fences.push(current_fence);
+                    break;
+                }
+            }
+        }
+
+        // Fix counter in case of "double corner"
+        let mut fence_positions: HashMap<Vector2d, usize> = HashMap::new();
+        for fence in &self.unit_fences {
+            fence_positions.insert(fence.pos1, self.count_fence_pos(&fence.pos1));
+            fence_positions.insert(fence.pos2, self.count_fence_pos(&fence.pos2));
+        }
+
+        fences_count += 2 * fence_positions
+            .into_iter()
+            .filter(|(_, counter)| *counter == 4)
+            .count();
+
+        fences_count
+    }
+}
+
+struct SearchContext {
+    processed: HashSet<Vector2d>,
+}
+
+impl SearchContext {
+    fn new() -> SearchContext {
+        SearchContext {
+            processed: HashSet::new(),
+        }
+    }
+}
+
+/// Area multiplied by the perimeter, or by the number of sides (bulk discount)
+pub struct Price {
+    pub with_perimeter: usize,
+    pub with_fences: usize,
+}
+
+impl Price {
+    fn add(&mut self, price: Price) {
+        self.with_perimeter += price.with_perimeter;
+        self.with_fences += price.with_fences;
+    }
+}
+
+/// Map of the garden plots
+pub struct Garden {
+    plants: Grid<Plant>,
+}
+
+impl Garden {
+    pub fn plants(&self) -> &Grid<Plant> {
+        &self.plants
+    }
+
+    /// Total price of the regions
+    pub fn prices(&self) -> Price {
+        let mut search_context: SearchContext = SearchContext::new();
+
+        let mut price: Price = Price {
+            with_perimeter: 0,
+            with_fences: 0,
+        };
+
+        for (pos, plant) in self.plants.iter() {
+            if !search_context.processed.contains(&pos) {
+                let region_price = self.compute_region_price(*plant, &pos, &mut search_context);
+
+                price.add(region_price);
+            }
+        }
+
+        price
+    }
+
+    fn compute_region_price(
+        &self,
+        plant: Plant,
+        pos: &Vector2d,
+        context: &mut SearchContext,
+    ) -> Price {
+        let region = self.compute_region_infos(plant, pos);
+
+        for pos in &region.positions {
+            context.processed.insert(*pos);
+        }
+
+        region.price()
+    }
+
+    // Flood fill from a plot, with an explicit stack: a region can cover the whole garden
+    fn compute_region_infos(&self, plant: Plant, start_pos: &Vector2d) -> Region {
+        let mut region = Region::new(plant);
+        region.positions.insert(*start_pos);
+        let mut positions_to_visit = vec![*start_pos];
+
+        while let Some(pos) = positions_to_visit.pop() {
+            region.perimeter += self.get_perimeter(&pos, plant);
+
+            for dir in Direction::CARDINALS {
+                let next_pos = pos + dir;
+                if self.plants.get(&next_pos) == Some(&plant) {
+                    // Grow region
+                    if region.positions.insert(next_pos) {
+                        positions_to_visit.push(next_pos);
+                    }
+                    continue;
+                }
+
+                // Add fence (scale positions just to keep integer vectors...)
+                let v: Vector2d = dir.turn_right().to_vector();
+                let dir: Vector2d = dir.to_vector();
+                let pos1 = pos * 2 + dir + v;
+                let pos2 = pos * 2 + dir - v;
+                region.unit_fences.push(Fence { pos1, pos2 });
+            }
+        }
+
+        region
+    }
+
+    /// Regions, in the order of their first plot (row by row)
+    pub fn regions(&self) -> Vec<Region> {
+        let mut region_map = self.plants.map(|_, _| false);
+        let mut regions = vec![];
+
+        for (pos, plant) in self.plants.iter() {
+            if region_map[pos] {
+                continue;
+            }
+
+            let region = self.compute_region_infos(*plant, &pos);
+            for region_pos in &region.positions {
+                region_map[*region_pos] = true;
+            }
+            regions.push(region);
+        }
+
+        regions
+    }
+
+    /// Index of the region of every plot
+    pub fn region_map(&self) -> Grid<usize> {
+        let mut region_map = self.plants.map(|_, _| 0);
+        for (index, region) in self.regions().iter().enumerate() {
+            for pos in &region.positions {
+                region_map[*pos] = index;
+            }
+        }
+
+        region_map
+    }
+
+    fn get_perimeter(&self, pos: &Vector2d, plant: Plant) -> usize {
+        Direction::CARDINALS
+            .into_iter()
+            .filter(|dir| {
+                let next_pos = *pos + *dir;
+                match self.plants.get(&next_pos) {
+                    Some(other_plant) => *other_plant != plant,
+                    None => true,
+                }
+            })
+            .count()
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<Garden, ParseError> {
+    Ok(Garden {
+        plants: Grid::try_parse(raw_data, Some, "a plant")?,
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Garden;
+
+    fn parse(raw_data: &str) -> Result<Garden, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(garden: &Garden) -> Answer {
+        garden.prices().with_perimeter.into()
+    }
+
+    fn part2(garden: &Garden) -> Answer {
+        garden.prices().with_fences.into()
+    }
+}
+
+impl Visualize for Day12 {
+    // One colour per region
+    fn draw(garden: &Garden, canvas: &mut dyn Canvas) -> io::Result<()> {
+        let regions = garden.region_map();
+        canvas.still(&render::colorize(&regions, &|region: &usize| {
+            Rgb::from_id(*region as u64)
+        }))
+    }
+}
+
+impl Generate for Day12 {
+    // Side of the garden
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        const PLANTS: &[char] = &[
+            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+        ];
+
+        // Regions grow from random seeds in random directions, which gives irregular shapes
+        let side = size.max(1);
+        let regions_count = (side * side / difficulty.pick(200, 100, 40)).max(1);
+        let stray_plant_probability = difficulty.pick(0.0, 0.01, 0.05);
+
+        let mut garden: Grid<Option<char>> = Grid::new(side, side, None);
+        let mut frontier = vec![];
+        for _ in 0..regions_count {
+            let pos = Vector2d::new(rng.below(side) as i64, rng.below(side) as i64);
+            garden.set(&pos, Some(*rng.pick(PLANTS)));
+            frontier.push(pos);
+        }
+
+        while !frontier.is_empty() {
+            let pos = frontier.swap_remove(rng.below(frontier.len()));
+            let plant = garden[pos];
+            let free_positions: Vec<Vector2d> = garden
+                .neighbours4(&pos)
+                .filter(|next_pos| garden[*next_pos].is_none())
+                .collect();
+            for next_pos in free_positions {
+                garden.set(&next_pos, plant);
+                frontier.push(next_pos);
+            }
+        }
+
+        // Single plots inside the regions
+        garden
+            .map(|_, plant| match rng.chance(stray_plant_probability) {
+                true => *rng.pick(PLANTS),
+                false => plant.unwrap(), // Every plot is reached from a seed
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const SMALL_EXAMPLE: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const E_SHAPED_EXAMPLE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const INNER_REGIONS_EXAMPLE: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), Answer::from(1930));
+    }
+
+    #[test]
+    fn single_region_garden() {
+        // A region covering the whole garden must not overflow the stack
+        let side = 140;
+        let input = Day12::parse(&format!("{}\n", "A".repeat(side)).repeat(side)).unwrap();
+        assert_eq!(Day12::part1(&input), Answer::from(side * side * 4 * side));
+        assert_eq!(Day12::part2(&input), Answer::from(side * side * 4));
+    }
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day12::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), Answer::from(140));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::from(1206));
+    }
+
+    #[test]
+    fn part2_small_example() {
+        let input = Day12::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::from(80));
+    }
+
+    #[test]
+    fn part2_e_shaped_example() {
+        let input = Day12::parse(E_SHAPED_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::from(236));
+    }
+
+    #[test]
+    fn part2_inner_regions_example() {
+        let input = Day12::parse(INNER_REGIONS_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::from(368));
+    }
+}
@@ -0,0 +1,389 @@
+//! Day 13: Claw Contraption, cheapest button presses to win the prizes
+
+#[cfg(feature = "serde")]
+use aoc_core::Dump;
+use aoc_core::{
+    Answer, Difficulty, Generate, Param, Params, ParseError, Parser, Rng, Solution, Vector2d,
+};
+
+const BUTTON_A_COST: i64 = 3;
+const BUTTON_B_COST: i64 = 1;
+
+// x1*y1 - x2*y2, None if it overflows
+fn cross(x1: i64, y1: i64, x2: i64, y2: i64) -> Option<i64> {
+    x1.checked_mul(y1)?.checked_sub(x2.checked_mul(y2)?)
+}
+
+// Move of the claw on an axis after pressing a times the button A and b times the button B
+fn presses_move(a: i64, a_move: i64, b: i64, b_move: i64) -> Option<i64> {
+    a.checked_mul(a_move)?.checked_add(b.checked_mul(b_move)?)
+}
+
+fn cost(a: i64, b: i64) -> Option<i64> {
+    presses_move(a, BUTTON_A_COST, b, BUTTON_B_COST)
+}
+
+/// Moves of the claw for each button, and position of the prize
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClawMachine {
+    pub a_move: Vector2d,
+    pub b_move: Vector2d,
+    pub prize: Vector2d,
+}
+
+impl ClawMachine {
+    /// Tokens needed to win the prize (moved by value_to_add on both axes), if it can be won:
+    /// solves the linear system of the button presses. Overflows are handled as prizes which
+    /// can't be won, as are collinear buttons (never found in puzzle inputs), which have no
+    /// single solution
+    pub fn minimal_cost_optimized(&self, value_to_add: i64) -> Option<i64> {
+        let prize = Vector2d {
+            x: self.prize.x.checked_add(value_to_add)?,
+            y: self.prize.y.checked_add(value_to_add)?,
+        };
+
+        // Solving a*a_move + b*b_move = prize
+        let num = cross(prize.x, self.a_move.y, prize.y, self.a_move.x)?;
+        let den = cross(self.b_move.x, self.a_move.y, self.b_move.y, self.a_move.x)?;
+        // None for a null denominator too
+        if num.checked_rem(den)? != 0 {
+            return None;
+        }
+
+        let b = num.checked_div(den)?;
+
+        let num = prize.x.checked_sub(b.checked_mul(self.b_move.x)?)?;
+        let den = self.a_move.x;
+        if num.checked_rem(den)? != 0 {
+            return None;
+        }
+
+        let a = num.checked_div(den)?;
+
+        // Buttons can't be pressed a negative number of times
+        if a < 0 || b < 0 {
+            return None;
+        }
+
+        cost(a, b)
+    }
+
+    /// Tokens needed to win the prize, trying up to 100 presses of each button
+    pub fn minimal_cost_simple(&self) -> Option<i64> {
+        let mut lower_cost: Option<i64> = None;
+
+        for a in 0..=100 {
+            for b in 0..=100 {
+                let Some(c) = presses_move(a, self.a_move.x, b, self.b_move.x) else {
+                    continue;
+                };
+                if c == 0 {
+                    continue;
+                }
+
+                if 0 == self.prize.x % c {
+                    let k = self.prize.x / c;
+                    let Some(d) = presses_move(a, self.a_move.y, b, self.b_move.y) else {
+                        continue;
+                    };
+
+                    if d == 0 {
+                        continue;
+                    }
+
+                    if 0 == self.prize.y % d && self.prize.y / d == k {
+                        let (Some(a_count), Some(b_count)) = (k.checked_mul(a), k.checked_mul(b))
+                        else {
+                            continue;
+                        };
+                        let Some(cost) = cost(a_count, b_count) else {
+                            continue;
+                        };
+
+                        lower_cost = match lower_cost {
+                            Some(other_cost) => {
+                                if cost < other_cost {
+                                    Some(cost)
+                                } else {
+                                    Some(other_cost)
+                                }
+                            }
+                            None => Some(cost),
+                        }
+                    }
+                }
+            }
+        }
+
+        lower_cost
+    }
+}
+
+fn parse_vector(
+    parser: &mut Parser,
+    x_prefix: &str,
+    y_prefix: &str,
+) -> Result<Vector2d, ParseError> {
+    parser.literal(x_prefix)?;
+    let x = parser.unsigned()?;
+    parser.literal(y_prefix)?;
+    let y = parser.unsigned()?;
+
+    Ok(Vector2d { x, y })
+}
+
+fn parse_claw_machine(mut section: Parser) -> Result<ClawMachine, ParseError> {
+    section.literal("Button A: ")?;
+    let a_move = parse_vector(&mut section, "X+", ", Y+")?;
+    section.literal("\nButton B: ")?;
+    let b_move = parse_vector(&mut section, "X+", ", Y+")?;
+    section.literal("\nPrize: ")?;
+    let prize = parse_vector(&mut section, "X=", ", Y=")?;
+    section.end()?;
+
+    Ok(ClawMachine {
+        a_move,
+        b_move,
+        prize,
+    })
+}
+
+/// Move of the prizes in part 2, on both axes (puzzle parameter)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrizeParams {
+    pub prize_offset: i64,
+}
+
+impl PrizeParams {
+    fn new(params: &Params) -> PrizeParams {
+        PrizeParams {
+            prize_offset: params.get("prize_offset"),
+        }
+    }
+}
+
+/// The claw machines of the arcade
+pub struct Arcade {
+    pub machines: Vec<ClawMachine>,
+    pub params: PrizeParams,
+}
+
+fn parse_input(raw_data: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    Parser::new(raw_data)
+        .sections()
+        .into_iter()
+        .map(parse_claw_machine)
+        .collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    // Move of the prizes in part 2, on both axes
+    const PARAMS: &'static [Param] = &[Param::new(
+        "prize_offset",
+        10000000000000,
+        0..=10000000000000,
+    )];
+
+    type Input = Arcade;
+
+    fn parse(raw_data: &str) -> Result<Arcade, ParseError> {
+        Self::parse_with(raw_data, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(raw_data: &str, params: &Params) -> Result<Arcade, ParseError> {
+        Ok(Arcade {
+            machines: parse_input(raw_data)?,
+            params: PrizeParams::new(params),
+        })
+    }
+
+    fn part1(arcade: &Arcade) -> Answer {
+        arcade
+            .machines
+            .iter()
+            .filter_map(|claw_machine| claw_machine.minimal_cost_simple())
+            .try_fold(0_i64, i64::checked_add)
+            .into()
+    }
+
+    fn part2(arcade: &Arcade) -> Answer {
+        arcade
+            .machines
+            .iter()
+            .filter_map(|claw_machine| {
+                claw_machine.minimal_cost_optimized(arcade.params.prize_offset)
+            })
+            .try_fold(0_i64, i64::checked_add)
+            .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Dump for Day13 {
+    fn model(arcade: &Arcade) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(&arcade.machines)
+    }
+}
+
+impl Generate for Day13 {
+    // Claw machines
+    const DEFAULT_SIZE: usize = 320;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let reachable_probability = difficulty.pick(0.8, 0.5, 0.3);
+
+        let machines: Vec<String> = (0..size)
+            .map(|_| {
+                let a = Vector2d::new(rng.between(10, 99), rng.between(10, 99));
+                // Like puzzle inputs, buttons never move in the same direction
+                let b = loop {
+                    let b = Vector2d::new(rng.between(10, 99), rng.between(10, 99));
+                    if a.x * b.y != a.y * b.x {
+                        break b;
+                    }
+                };
+                let prize = match rng.chance(reachable_probability) {
+                    true => a * rng.between(0, 100) + b * rng.between(0, 100),
+                    false => Vector2d::new(rng.between(1000, 20000), rng.between(1000, 20000)),
+                };
+
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    a.x, a.y, b.x, b.y, prize.x, prize.y
+                )
+            })
+            .collect();
+
+        machines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), Answer::from(480));
+    }
+
+    #[test]
+    fn negative_presses_are_rejected() {
+        // Solved by pressing A -1 times and B 3 times
+        let claw_machine = ClawMachine {
+            a_move: Vector2d { x: 2, y: 1 },
+            b_move: Vector2d { x: 1, y: 2 },
+            prize: Vector2d { x: 1, y: 5 },
+        };
+        assert_eq!(claw_machine.minimal_cost_simple(), None);
+        assert_eq!(claw_machine.minimal_cost_optimized(0), None);
+    }
+
+    #[test]
+    fn overflowing_costs_are_unsolved() {
+        // Pressing A 2^62 times wins the prize, which costs more than an i64
+        let claw_machine = ClawMachine {
+            a_move: Vector2d { x: 1, y: 1 },
+            b_move: Vector2d { x: 1, y: 2 },
+            prize: Vector2d::new(1 << 62, 1 << 62),
+        };
+        assert_eq!(claw_machine.minimal_cost_optimized(0), None);
+        assert_eq!(claw_machine.minimal_cost_optimized(i64::MAX), None);
+
+        let max = i64::MAX;
+        let input = Day13::parse(&format!(
+            "Button A: X+{max}, Y+{max}\nButton B: X+{max}, Y+1\nPrize: X={max}, Y={max}\n"
+        ))
+        .unwrap();
+        assert_eq!(Day13::part1(&input), Answer::from(3));
+        assert_eq!(Day13::part2(&input), Answer::from(0));
+    }
+
+    fn button_move() -> impl Strategy<Value = Vector2d> {
+        (1..100_i64, 1..100_i64).prop_map(|(x, y)| Vector2d { x, y })
+    }
+
+    // The optimized method only applies when the buttons are not collinear (single solution),
+    // as generated
+    fn is_solvable(a_move: &Vector2d, b_move: &Vector2d) -> bool {
+        a_move.x * b_move.y != a_move.y * b_move.x
+    }
+
+    #[test]
+    fn generated_buttons_are_not_collinear() {
+        let raw_data = Day13::generate(&mut aoc_core::Rng::new(1), 1000, Difficulty::Hard);
+        let arcade = Day13::parse(&raw_data).unwrap();
+        for machine in &arcade.machines {
+            assert!(is_solvable(&machine.a_move, &machine.b_move));
+            if let Some(cost) = machine.minimal_cost_simple() {
+                assert_eq!(machine.minimal_cost_optimized(0), Some(cost));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn reachable_prizes_cost_the_same(
+            a_move in button_move(),
+            b_move in button_move(),
+            a_count in 0..=100_i64,
+            b_count in 0..=100_i64,
+        ) {
+            prop_assume!(is_solvable(&a_move, &b_move));
+
+            let claw_machine = ClawMachine {
+                a_move,
+                b_move,
+                prize: a_move * a_count + b_move * b_count,
+            };
+            let cost = a_count * BUTTON_A_COST + b_count * BUTTON_B_COST;
+            prop_assert_eq!(claw_machine.minimal_cost_simple(), Some(cost));
+            prop_assert_eq!(claw_machine.minimal_cost_optimized(0), Some(cost));
+        }
+
+        #[test]
+        fn any_prize_costs_the_same(
+            a_move in button_move(),
+            b_move in button_move(),
+            prize_x in 0..20_000_i64,
+            prize_y in 0..20_000_i64,
+        ) {
+            prop_assume!(is_solvable(&a_move, &b_move));
+
+            let claw_machine = ClawMachine {
+                a_move,
+                b_move,
+                prize: Vector2d { x: prize_x, y: prize_y },
+            };
+            // The simple method is limited to 100 presses (or multiples of a solution under 100)
+            if let Some(cost) = claw_machine.minimal_cost_simple() {
+                prop_assert_eq!(claw_machine.minimal_cost_optimized(0), Some(cost));
+            }
+        }
+    }
+}
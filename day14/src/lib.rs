@@ -0,0 +1,420 @@
+//! Day 14: Restroom Redoubt, robots moving around a bathroom area
+
+#[cfg(feature = "serde")]
+use aoc_core::Dump;
+use aoc_core::{
+    Answer, Canvas, Difficulty, Generate, Param, Params, ParseError, Parser, Rgb, Rng, Solution,
+    Vector2d, Visualize,
+};
+use std::collections::HashMap;
+use std::io;
+
+#[derive(Hash, Eq, PartialEq)]
+enum Quadrant {
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+/// Position, and velocity in tiles per second
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Robot {
+    pub pos: Vector2d,
+    pub v: Vector2d,
+}
+
+impl Robot {
+    fn move_robot(&mut self, grid_size: Vector2d) {
+        self.pos = Vector2d {
+            x: (self.pos.x + self.v.x).rem_euclid(grid_size.x),
+            y: (self.pos.y + self.v.y).rem_euclid(grid_size.y),
+        };
+    }
+}
+
+/// The robots in the area, wrapping around its edges
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid {
+    size: Vector2d,
+    robots: Vec<Robot>,
+}
+
+impl Grid {
+    pub fn size(&self) -> Vector2d {
+        self.size
+    }
+
+    pub fn robots(&self) -> &[Robot] {
+        &self.robots
+    }
+
+    /// Safety factor after the given number of seconds
+    pub fn simulate(&mut self, n_steps: usize) -> usize {
+        for _ in 0..n_steps {
+            self.move_robots();
+        }
+
+        self.safety_factor()
+    }
+
+    /// One second of the simulation
+    pub fn move_robots(&mut self) {
+        for robot in &mut self.robots {
+            robot.move_robot(self.size);
+        }
+    }
+
+    /// Seconds until the robots draw the Christmas tree, if it shows up in time
+    pub fn find_easter_egg(&mut self, n_steps_max: usize) -> Option<usize> {
+        for step in 0..n_steps_max {
+            self.move_robots();
+
+            if self.has_aligned_robots() {
+                return Some(step + 1);
+            }
+        }
+
+        None
+    }
+
+    // The easter egg is drawn by the robots: search for enough consecutive robots in a column
+    fn has_aligned_robots(&self) -> bool {
+        // Column by column, from top to bottom
+        let mut positions: Vec<(i64, i64)> = self
+            .robots
+            .iter()
+            .map(|robot| (robot.pos.x, robot.pos.y))
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+
+        let mut consecutive_robots = 0;
+        let mut last_pos = None;
+        for (x, y) in positions {
+            match last_pos == Some((x, y - 1)) {
+                true => consecutive_robots += 1,
+                false => consecutive_robots = 1,
+            }
+            if consecutive_robots > 8 {
+                return true;
+            }
+            last_pos = Some((x, y));
+        }
+
+        false
+    }
+
+    // Robots in green on a black background
+    fn picture(&self) -> aoc_core::Grid<Rgb> {
+        let mut image = aoc_core::Grid::new(self.size.x as usize, self.size.y as usize, Rgb::BLACK);
+        for robot in &self.robots {
+            image.set(&robot.pos, Rgb::GREEN);
+        }
+
+        image
+    }
+
+    fn get_quadrant(&self, pos: Vector2d) -> Quadrant {
+        let middle_x = (self.size.x - 1) / 2;
+        let middle_y = (self.size.y - 1) / 2;
+
+        if pos.x < middle_x {
+            if pos.y < middle_y {
+                Quadrant::UpLeft
+            } else {
+                Quadrant::DownLeft
+            }
+        } else {
+            if pos.y < middle_y {
+                Quadrant::UpRight
+            } else {
+                Quadrant::DownRight
+            }
+        }
+    }
+
+    /// Product of the robot counts of the 4 quadrants (robots in the middle are ignored)
+    pub fn safety_factor(&self) -> usize {
+        let mut count_by_quadrant: HashMap<Quadrant, usize> = HashMap::new();
+
+        let middle_x = (self.size.x - 1) / 2;
+        let middle_y = (self.size.y - 1) / 2;
+
+        for robot in &self.robots {
+            // Exclude robots in the middle
+            if robot.pos.x == middle_x || robot.pos.y == middle_y {
+                continue;
+            }
+
+            let quadrant = self.get_quadrant(robot.pos);
+            *count_by_quadrant.entry(quadrant).or_default() += 1_usize;
+        }
+
+        count_by_quadrant.values().product()
+    }
+}
+
+fn parse_vector(parser: &mut Parser) -> Result<Vector2d, ParseError> {
+    let x = parser.signed()?;
+    parser.literal(",")?;
+    let y = parser.signed()?;
+
+    Ok(Vector2d { x, y })
+}
+
+/// Robots of an area of the given size (the examples are in a smaller area)
+pub fn parse_input(raw_data: &str, grid_size: Vector2d) -> Result<Grid, ParseError> {
+    let robots = Parser::new(raw_data).parse_lines(|line| {
+        let start = *line;
+        let pos = line.key_value("p", parse_vector)?;
+        if !(0..grid_size.x).contains(&pos.x) || !(0..grid_size.y).contains(&pos.y) {
+            return Err(start.error(format!(
+                "a position inside the {}x{} area",
+                grid_size.x, grid_size.y
+            )));
+        }
+        line.literal(" ")?;
+        // Robots can't cross the whole area in a single second
+        let start = *line;
+        let v = line.key_value("v", parse_vector)?;
+        if v.x.abs() > grid_size.x || v.y.abs() > grid_size.y {
+            return Err(start.error(format!(
+                "a velocity up to the {}x{} area",
+                grid_size.x, grid_size.y
+            )));
+        }
+
+        Ok(Robot { pos, v })
+    })?;
+
+    Ok(Grid {
+        robots,
+        size: grid_size,
+    })
+}
+
+pub const GRID_SIZE: Vector2d = Vector2d::new(101, 103);
+
+/// Values of the puzzle parameters, the examples use a smaller area
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RobotParams {
+    pub size: Vector2d,
+    pub steps: usize,
+    pub max_steps: usize,
+}
+
+impl RobotParams {
+    fn new(params: &Params) -> RobotParams {
+        RobotParams {
+            size: Vector2d::new(params.get("width"), params.get("height")),
+            steps: params.get("steps"),
+            max_steps: params.get("max_steps"),
+        }
+    }
+}
+
+/// The robots of the bathroom area
+pub struct Bathroom {
+    pub grid: Grid,
+    pub params: RobotParams,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    // Size of the area, seconds before the safety factor and seconds searched for the easter egg
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", GRID_SIZE.x as u64, 1..=1000),
+        Param::new("height", GRID_SIZE.y as u64, 1..=1000),
+        Param::new("steps", 100, 0..=1000000),
+        Param::new("max_steps", 10000, 0..=1000000),
+    ];
+
+    type Input = Bathroom;
+
+    fn parse(raw_data: &str) -> Result<Bathroom, ParseError> {
+        Self::parse_with(raw_data, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(raw_data: &str, params: &Params) -> Result<Bathroom, ParseError> {
+        let params = RobotParams::new(params);
+        Ok(Bathroom {
+            grid: parse_input(raw_data, params.size)?,
+            params,
+        })
+    }
+
+    fn part1(bathroom: &Bathroom) -> Answer {
+        let mut grid = bathroom.grid.clone();
+        grid.simulate(bathroom.params.steps).into()
+    }
+
+    fn part2(bathroom: &Bathroom) -> Answer {
+        let mut grid = bathroom.grid.clone();
+        grid.find_easter_egg(bathroom.params.max_steps).into()
+    }
+}
+
+impl Visualize for Day14 {
+    // Every second of the simulation, until the easter egg shows up
+    fn draw(bathroom: &Bathroom, canvas: &mut dyn Canvas) -> io::Result<()> {
+        let mut grid = bathroom.grid.clone();
+
+        canvas.frame(&grid.picture())?;
+        for _ in 0..bathroom.params.max_steps {
+            grid.move_robots();
+            canvas.frame(&grid.picture())?;
+
+            if grid.has_aligned_robots() {
+                break;
+            }
+        }
+
+        canvas.still(&grid.picture())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Dump for Day14 {
+    fn model(bathroom: &Bathroom) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(&bathroom.grid)
+    }
+}
+
+impl Generate for Day14 {
+    // Robots
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        Self::generate_with(rng, size, difficulty, &Params::defaults(Self::PARAMS))
+    }
+
+    fn generate_with(
+        rng: &mut Rng,
+        size: usize,
+        difficulty: Difficulty,
+        params: &Params,
+    ) -> String {
+        let area = RobotParams::new(params).size;
+
+        // Some robots draw a frame after a given number of seconds (if there are enough
+        // robots for its 9 robots high sides, and room for it), their start positions are
+        // found by moving back in time
+        let egg_seconds = rng.between(1, difficulty.pick(100, 1000, 2000));
+        let frame_side = difficulty.pick(20, 15, 10).min(size as i64 / 4 + 1).max(9);
+        let frame_fits = frame_side <= area.x && frame_side <= area.y;
+        let frame_origin = Vector2d::new(
+            rng.between(0, (area.x - frame_side).max(0)),
+            rng.between(0, (area.y - frame_side).max(0)),
+        );
+        let mut frame: Vec<Vector2d> = (0..frame_side)
+            .flat_map(|x| (0..frame_side).map(move |y| Vector2d::new(x, y)))
+            .filter(|pos| {
+                pos.x == 0 || pos.y == 0 || pos.x == frame_side - 1 || pos.y == frame_side - 1
+            })
+            .map(|pos| frame_origin + pos)
+            .collect();
+        if frame.len() > size || !frame_fits {
+            frame.clear();
+        }
+
+        let robots: Vec<String> = (0..size)
+            .map(|index| {
+                let v_max = Vector2d::new(area.x.min(99), area.y.min(99));
+                let v = Vector2d::new(
+                    rng.between(-v_max.x, v_max.x),
+                    rng.between(-v_max.y, v_max.y),
+                );
+                let pos = match frame.get(index) {
+                    Some(egg_pos) => Vector2d::new(
+                        (egg_pos.x - v.x * egg_seconds).rem_euclid(area.x),
+                        (egg_pos.y - v.y * egg_seconds).rem_euclid(area.y),
+                    ),
+                    None => Vector2d::new(rng.between(0, area.x - 1), rng.between(0, area.y - 1)),
+                };
+
+                format!("p={},{} v={},{}\n", pos.x, pos.y, v.x, v.y)
+            })
+            .collect();
+
+        robots.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn safety_factor_example() {
+        let mut grid = parse_input(EXAMPLE, Vector2d::new(11, 7)).unwrap();
+        assert_eq!(grid.simulate(100), 12);
+    }
+
+    #[test]
+    fn part1_example() {
+        let config = aoc_core::Config::parse("[day14]\nwidth = 11\nheight = 7\n").unwrap();
+        let params = config.params(14, Day14::PARAMS).unwrap();
+        let input = Day14::parse_with(EXAMPLE, &params).unwrap();
+        assert_eq!(Day14::part1(&input), Answer::from(12));
+    }
+
+    // The example has no easter egg: 9 robots of a column are aligned once the one in the middle
+    // comes back in place, after 3 seconds
+    const EASTER_EGG: &str = "\
+p=5,0 v=0,0
+p=5,1 v=0,0
+p=5,2 v=0,0
+p=5,3 v=0,0
+p=2,4 v=1,0
+p=5,5 v=0,0
+p=5,6 v=0,0
+p=5,7 v=0,0
+p=5,8 v=0,0
+";
+
+    #[test]
+    fn part2_easter_egg() {
+        let input = Day14::parse(EASTER_EGG).unwrap();
+        assert_eq!(Day14::part2(&input), Answer::from(3));
+
+        // Not found in time
+        let mut grid = input.grid.clone();
+        assert_eq!(grid.find_easter_egg(2), None);
+    }
+
+    #[test]
+    fn generated_inputs_fit_the_area() {
+        for (width, height) in [(11, 7), (31, 31)] {
+            let config =
+                aoc_core::Config::parse(&format!("[day14]\nwidth = {width}\nheight = {height}\n"))
+                    .unwrap();
+            let params = config.params(14, Day14::PARAMS).unwrap();
+            let raw_data = Day14::generate_with(&mut Rng::new(1), 500, Difficulty::Normal, &params);
+            let input = Day14::parse_with(&raw_data, &params).unwrap();
+            // Only the larger area has room for the easter egg
+            assert_eq!(Day14::part2(&input) != Answer::Unsolved, width > 11);
+        }
+    }
+}
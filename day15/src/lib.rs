@@ -0,0 +1,503 @@
+//! Day 15: Warehouse Woes, a robot pushing boxes around a warehouse
+
+#[cfg(feature = "serde")]
+use aoc_core::Dump;
+use aoc_core::{
+    render, Answer, Canvas, Difficulty, Direction, Generate, Grid, ParseError, Parser, Rgb, Rng,
+    Solution, Vector2d, Visualize,
+};
+use std::collections::HashMap;
+use std::io;
+
+/// Boxes are one tile wide, or two in scaled warehouses (left and right sides)
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Box {
+    Default,
+    LeftSide,
+    RightSide,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cell {
+    Empty,
+    Wall,
+    Box(Box),
+}
+
+/// The warehouse map, and the moves of the robot
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Warehouse {
+    cells: Grid<Cell>,
+    scaled: bool,
+
+    robot_start_pos: Vector2d,
+    robot_instructions: Vec<Direction>,
+}
+
+impl Warehouse {
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Everything except the robot is twice as wide
+    pub fn is_scaled(&self) -> bool {
+        self.scaled
+    }
+
+    pub fn robot_start_pos(&self) -> Vector2d {
+        self.robot_start_pos
+    }
+
+    pub fn robot_instructions(&self) -> &[Direction] {
+        &self.robot_instructions
+    }
+
+    /// Same warehouse with every wall, box and free space twice as wide
+    pub fn scaled(&self) -> Warehouse {
+        let mut cells = Grid::new(2 * self.cells.width(), self.cells.height(), Cell::Empty);
+        for (pos, cell) in self.cells.iter() {
+            let (left, right) = match cell {
+                Cell::Box(_) => (Cell::Box(Box::LeftSide), Cell::Box(Box::RightSide)),
+                _ => (*cell, *cell),
+            };
+            let left_pos = Vector2d::new(2 * pos.x, pos.y);
+            cells.set(&left_pos, left);
+            cells.set(&(left_pos + Direction::Right), right);
+        }
+
+        Warehouse {
+            cells,
+            scaled: true,
+            robot_start_pos: Vector2d::new(2 * self.robot_start_pos.x, self.robot_start_pos.y),
+            robot_instructions: self.robot_instructions.clone(),
+        }
+    }
+
+    fn is_expected_cell(&self, pos: &Vector2d, cell: Cell) -> bool {
+        if let Some(other_cell) = self.cells.get(pos) {
+            if cell != *other_cell {
+                return false;
+            }
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    fn is_consistent(&self) -> bool {
+        for (pos, cell) in self.cells.iter() {
+            match cell {
+                Cell::Box(Box::LeftSide) => {
+                    let other_pos = pos + Direction::Right;
+                    if !self.is_expected_cell(&other_pos, Cell::Box(Box::RightSide)) {
+                        return false;
+                    }
+                }
+                Cell::Box(Box::RightSide) => {
+                    let other_pos = pos + Direction::Left;
+                    if !self.is_expected_cell(&other_pos, Cell::Box(Box::LeftSide)) {
+                        return false;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        true
+    }
+
+    fn picture(&self, robot_pos: &Vector2d) -> Grid<Rgb> {
+        let mut image = render::colorize(&self.cells, &|cell: &Cell| match cell {
+            Cell::Empty => Rgb::BLACK,
+            Cell::Wall => Rgb::GREY,
+            Cell::Box(_) => Rgb::BROWN,
+        });
+        image.set(robot_pos, Rgb::RED);
+
+        image
+    }
+
+    fn try_move_box(
+        &mut self,
+        box_cell: &Cell,
+        pos: &Vector2d,
+        dir: &Direction,
+        next_state: &mut HashMap<Vector2d, Cell>,
+    ) -> bool {
+        // Get 2 parts of the box
+        let mut box_parts = vec![(*pos, *box_cell)];
+        if let Cell::Box(cell_box) = box_cell {
+            let other_box_side = match cell_box {
+                Box::LeftSide => (*pos + Direction::Right, Cell::Box(Box::RightSide)),
+                Box::RightSide => (*pos + Direction::Left, Cell::Box(Box::LeftSide)),
+                Box::Default => {
+                    panic!("Box::Default type should not be used with scaled grids");
+                }
+            };
+
+            box_parts.push(other_box_side);
+        }
+
+        // Try to move box parts
+        for (box_pos, _) in &box_parts {
+            let next_pos = *box_pos + *dir;
+
+            let next_cell = *self.cells.get(&next_pos).unwrap();
+            match next_cell {
+                Cell::Box(_) => match self.try_move_box(&next_cell, &next_pos, dir, next_state) {
+                    true => continue,
+                    false => {
+                        return false;
+                    }
+                },
+                Cell::Empty => {
+                    continue;
+                }
+                Cell::Wall => {
+                    return false; // Wall encountered => move is impossible
+                }
+            }
+        }
+
+        for (box_pos, cell) in &box_parts {
+            let next_pos = *box_pos + *dir;
+
+            next_state.insert(next_pos, *cell);
+
+            if next_state.get(box_pos).is_none() {
+                next_state.insert(*box_pos, Cell::Empty);
+            }
+        }
+
+        true
+    }
+
+    /// Moves the robot at the given position, pushing the boxes in front of it: the new
+    /// position of the robot, or None when a wall blocks the move
+    pub fn try_move(&mut self, pos: &Vector2d, dir: &Direction) -> Option<Vector2d> {
+        let mut next_pos: Vector2d = *pos + *dir;
+        let mut next_state: HashMap<Vector2d, Cell> = HashMap::new();
+        next_state.insert(next_pos, Cell::Empty);
+
+        loop {
+            let cell = *self.cells.get(&next_pos).unwrap();
+
+            match cell {
+                Cell::Box(_) => {
+                    // We have to manage down/up directions properly for scaled boxes
+                    if self.scaled && dir.is_vertical() {
+                        match self.try_move_box(&cell, &next_pos, dir, &mut next_state) {
+                            true => break,
+                            false => {
+                                return None;
+                            }
+                        }
+                    } else {
+                        let next_cell_pos = next_pos + *dir;
+                        next_state.insert(next_cell_pos, cell);
+                    }
+                }
+                Cell::Empty => {
+                    break; // End of the move
+                }
+                Cell::Wall => {
+                    return None; // Wall encountered => we do nothing
+                }
+            }
+
+            next_pos = next_pos + *dir;
+        }
+
+        // Apply next_state to the warehouse
+        for (pos, next_cell) in next_state {
+            self.cells.set(&pos, next_cell);
+        }
+
+        let next_robot_pos = *pos + *dir;
+        Some(next_robot_pos)
+    }
+
+    /// Sum of the GPS coordinates of the boxes (100 times the row plus the column)
+    pub fn sum_of_coordinates(&self) -> usize {
+        let mut sum = 0;
+        for (pos, cell) in self.cells.iter() {
+            match cell {
+                Cell::Box(Box::Default) | Cell::Box(Box::LeftSide) => {
+                    sum += pos.y as usize * 100 + pos.x as usize
+                }
+                _ => {}
+            }
+        }
+
+        sum
+    }
+
+    /// Runs all the robot moves, then gives the sum of the box coordinates
+    pub fn simulate(&mut self) -> usize {
+        let _span = tracing::debug_span!("simulate", scaled = self.scaled).entered();
+
+        let mut robot_pos = self.robot_start_pos;
+        let mut consistent = true;
+        let instructions = self.robot_instructions.clone();
+        for (step, next_move) in instructions.into_iter().enumerate() {
+            if let Some(next_pos) = self.try_move(&robot_pos, &next_move) {
+                robot_pos = next_pos;
+            }
+            tracing::trace!(step, direction = ?next_move, robot = %robot_pos, "moved");
+
+            // Checking every box is slow, only done when debugging (moves can be watched
+            // with the visualization), and reported once
+            if consistent && tracing::enabled!(tracing::Level::DEBUG) && !self.is_consistent() {
+                tracing::error!(step, "warehouse is inconsistent");
+                consistent = false;
+            }
+        }
+
+        self.sum_of_coordinates()
+    }
+}
+
+fn parse_grid(section: &Parser) -> Result<(Grid<Cell>, Vector2d), ParseError> {
+    let (chars, markers) = section.grid_with_markers(
+        &['@'],
+        |c| "#.O@".contains(c).then_some(c),
+        "`#`, `.`, `O` or `@`",
+    )?;
+    let Some(&robot_pos) = markers.get(&'@') else {
+        return Err(section.error_at_end("a robot `@`"));
+    };
+
+    // The robot and the boxes must never leave the warehouse
+    let size = chars.size();
+    if let Some((pos, _)) = chars.iter().find(|(pos, c)| {
+        let on_border = pos.x == 0 || pos.y == 0 || pos.x == size.x - 1 || pos.y == size.y - 1;
+        on_border && **c != '#'
+    }) {
+        let mut line = section.lines().nth(pos.y as usize).unwrap();
+        let mut column = 0;
+        line.take_while(|_| {
+            column += 1;
+            column <= pos.x
+        });
+        return Err(line.error("a wall `#` around the warehouse"));
+    }
+
+    let cells = chars.map(|_, c| match c {
+        '#' => Cell::Wall,
+        'O' => Cell::Box(Box::Default),
+        _ => Cell::Empty,
+    });
+
+    Ok((cells, robot_pos))
+}
+
+fn parse_instructions(section: &Parser) -> Result<Vec<Direction>, ParseError> {
+    let lines = section.parse_lines(|line| {
+        let moves = line.take_while(|c| Direction::from_char(c).is_some());
+        if !line.is_empty() {
+            return Err(line.error("a move (`^`, `>`, `v`, `<`)"));
+        }
+
+        Ok(moves
+            .chars()
+            .flat_map(Direction::from_char)
+            .collect::<Vec<_>>())
+    })?;
+
+    Ok(lines.concat())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+
+    type Input = Warehouse;
+
+    // The warehouse as mapped, part 2 scales it
+    fn parse(raw_data: &str) -> Result<Warehouse, ParseError> {
+        let [raw_grid, raw_instructions] = Parser::new(raw_data).sections_exact("robot moves")?;
+
+        let (cells, robot_start_pos) = parse_grid(&raw_grid)?;
+
+        let robot_instructions = parse_instructions(&raw_instructions)?;
+
+        Ok(Warehouse {
+            robot_instructions,
+            robot_start_pos,
+            cells,
+            scaled: false,
+        })
+    }
+
+    fn part1(warehouse: &Warehouse) -> Answer {
+        warehouse.clone().simulate().into()
+    }
+
+    fn part2(warehouse: &Warehouse) -> Answer {
+        warehouse.scaled().simulate().into()
+    }
+}
+
+impl Visualize for Day15 {
+    // Every move of the robot in the scaled warehouse
+    fn draw(warehouse: &Warehouse, canvas: &mut dyn Canvas) -> io::Result<()> {
+        let mut warehouse = warehouse.scaled();
+        let mut robot_pos = warehouse.robot_start_pos;
+
+        canvas.frame(&warehouse.picture(&robot_pos))?;
+        for next_move in warehouse.robot_instructions.clone() {
+            if let Some(next_pos) = warehouse.try_move(&robot_pos, &next_move) {
+                robot_pos = next_pos;
+            }
+            canvas.frame(&warehouse.picture(&robot_pos))?;
+        }
+
+        canvas.still(&warehouse.picture(&robot_pos))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Dump for Day15 {
+    // Warehouses of both parts
+    fn model(warehouse: &Warehouse) -> serde_json::Result<serde_json::Value> {
+        Ok(serde_json::json!({
+            "part1": serde_json::to_value(warehouse)?,
+            "part2": serde_json::to_value(warehouse.scaled())?,
+        }))
+    }
+}
+
+impl Generate for Day15 {
+    // Side of the warehouse
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        const MOVES_PER_LINE: usize = 1000;
+
+        let side = size.max(3);
+        let wall_probability = difficulty.pick(0.02, 0.05, 0.05);
+        let box_probability = difficulty.pick(0.1, 0.25, 0.4);
+        let moves_count = side * side * difficulty.pick(1, 4, 8);
+
+        let mut warehouse = Grid::new(side, side, '#');
+        for pos in warehouse.positions().collect::<Vec<_>>() {
+            let inside =
+                pos.x > 0 && pos.y > 0 && pos.x < side as i64 - 1 && pos.y < side as i64 - 1;
+            if !inside || rng.chance(wall_probability) {
+                continue;
+            }
+
+            let cell = match rng.chance(box_probability) {
+                true => 'O',
+                false => '.',
+            };
+            warehouse.set(&pos, cell);
+        }
+
+        let robot_pos = Vector2d::new(
+            rng.between(1, side as i64 - 2),
+            rng.between(1, side as i64 - 2),
+        );
+        warehouse.set(&robot_pos, '@');
+
+        let moves: Vec<char> = (0..moves_count)
+            .map(|_| rng.pick(&Direction::CARDINALS).to_char())
+            .collect();
+        let moves: Vec<String> = moves
+            .chunks(MOVES_PER_LINE)
+            .map(|line| line.iter().collect())
+            .collect();
+
+        format!("{warehouse}\n{}\n", moves.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    const SCALED_EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day15::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), Answer::from(2028));
+    }
+
+    #[test]
+    fn part1_large_example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), Answer::from(10092));
+    }
+
+    #[test]
+    fn part2_large_example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), Answer::from(9021));
+    }
+
+    #[test]
+    fn part2_scaled_example() {
+        let input = Day15::parse(SCALED_EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), Answer::from(618));
+    }
+
+    #[test]
+    fn single_robot() {
+        let err = Day15::parse("#####\n#@.@#\n#####\n\n<>\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
@@ -0,0 +1,291 @@
+//! Day 16: Reindeer Maze, lowest score paths from the start tile to the end tile
+
+use aoc_core::{
+    generate, render, search, Answer, Canvas, Difficulty, Direction, Generate, Grid, ParseError,
+    Rgb, Rng, Solution, Vector2d, Visualize,
+};
+use std::collections::HashSet;
+use std::io;
+
+const TURN_SCORE: usize = 1000;
+const MOVE_FORWARD_SCORE: usize = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct Reindeer {
+    pos: Vector2d,
+    dir: Direction,
+}
+
+impl Reindeer {
+    fn get_move_score(&self, move_dir: &Direction) -> usize {
+        // Move forward
+        if *move_dir == self.dir {
+            return MOVE_FORWARD_SCORE;
+        }
+
+        // 90° rotation + move
+        if *move_dir != self.dir.reverse() {
+            return TURN_SCORE + MOVE_FORWARD_SCORE;
+        }
+
+        panic!("Not supposed to go back");
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Cell {
+    Empty,
+    Wall,
+}
+
+/// Tiles of the maze, the reindeer starts facing east
+pub struct Maze {
+    cells: Grid<Cell>,
+
+    start_pos: Vector2d,
+    start_dir: Direction,
+    exit_pos: Vector2d,
+}
+
+impl Maze {
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn start_pos(&self) -> Vector2d {
+        self.start_pos
+    }
+
+    pub fn exit_pos(&self) -> Vector2d {
+        self.exit_pos
+    }
+
+    fn is_empty_cell(&self, pos: &Vector2d) -> bool {
+        let Some(cell) = self.cells.get(pos) else {
+            return false;
+        };
+
+        *cell == Cell::Empty
+    }
+
+    fn next_moves(&self, reindeer: &Reindeer) -> Vec<(Reindeer, usize)> {
+        Direction::CARDINALS
+            .into_iter()
+            .filter(|move_dir| *move_dir != reindeer.dir.reverse()) // Don't go backward
+            .map(|move_dir| Reindeer {
+                pos: reindeer.pos + move_dir,
+                dir: move_dir,
+            })
+            .filter(|next_reindeer| self.is_empty_cell(&next_reindeer.pos))
+            .map(|next_reindeer| (next_reindeer, reindeer.get_move_score(&next_reindeer.dir)))
+            .collect()
+    }
+
+    /// Lowest score (a move costs 1, a quarter turn 1000) and the tiles of the best paths,
+    /// `None` when the exit can't be reached
+    pub fn compute_lowest_score(&self) -> Option<(usize, HashSet<Vector2d>)> {
+        let start = Reindeer {
+            pos: self.start_pos,
+            dir: self.start_dir,
+        };
+
+        let search_result = search::dijkstra(start, |reindeer| self.next_moves(reindeer));
+
+        // The exit can be reached from any direction
+        let exits: Vec<Reindeer> = Direction::CARDINALS
+            .into_iter()
+            .map(|dir| Reindeer {
+                pos: self.exit_pos,
+                dir,
+            })
+            .filter(|exit| search_result.distance(exit).is_some())
+            .collect();
+
+        let lowest_score = exits
+            .iter()
+            .filter_map(|exit| search_result.distance(exit))
+            .min()?;
+
+        let best_exits: Vec<Reindeer> = exits
+            .into_iter()
+            .filter(|exit| search_result.distance(exit) == Some(lowest_score))
+            .collect();
+
+        let best_sits: HashSet<Vector2d> = search_result
+            .best_path_states(&best_exits)
+            .into_iter()
+            .map(|reindeer| reindeer.pos)
+            .collect();
+
+        Some((lowest_score, best_sits))
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<Maze, ParseError> {
+    let (cells, markers) = Grid::try_parse_with_markers(
+        raw_data,
+        &['S', 'E'],
+        |c| match c {
+            '.' | 'S' | 'E' => Some(Cell::Empty),
+            '#' => Some(Cell::Wall),
+            _ => None,
+        },
+        "`.`, `#`, `S` or `E`",
+    )?;
+
+    let Some(&start_pos) = markers.get(&'S') else {
+        return Err(ParseError::at_end(raw_data, "a start tile `S`"));
+    };
+    let Some(&exit_pos) = markers.get(&'E') else {
+        return Err(ParseError::at_end(raw_data, "an end tile `E`"));
+    };
+
+    Ok(Maze {
+        cells,
+        start_pos,
+        start_dir: Direction::Right,
+        exit_pos,
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = Maze;
+
+    fn parse(raw_data: &str) -> Result<Maze, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(maze: &Maze) -> Answer {
+        maze.compute_lowest_score()
+            .map(|(lowest_score, _)| lowest_score)
+            .into()
+    }
+
+    fn part2(maze: &Maze) -> Answer {
+        maze.compute_lowest_score()
+            .map(|(_, best_sits)| best_sits.len())
+            .into()
+    }
+}
+
+impl Visualize for Day16 {
+    // Tiles of the best paths through the maze
+    fn draw(maze: &Maze, canvas: &mut dyn Canvas) -> io::Result<()> {
+        let best_sits = maze
+            .compute_lowest_score()
+            .map(|(_, best_sits)| best_sits)
+            .unwrap_or_default();
+
+        let mut image = render::colorize(&maze.cells, &|cell: &Cell| match cell {
+            Cell::Empty => Rgb::BLACK,
+            Cell::Wall => Rgb::GREY,
+        });
+        render::highlight(&mut image, &best_sits, Rgb::YELLOW);
+        image.set(&maze.start_pos, Rgb::GREEN);
+        image.set(&maze.exit_pos, Rgb::RED);
+
+        canvas.still(&image)
+    }
+}
+
+impl Generate for Day16 {
+    // Side of the maze
+    const DEFAULT_SIZE: usize = 141;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // More loops give more paths with the best score
+        let loops = difficulty.pick(0.0, 0.05, 0.2);
+        let walls = generate::maze(rng, size, size, loops);
+
+        // Start in the bottom left corner, exit in the top right one
+        let start_pos = Vector2d::new(1, walls.height() as i64 - 2);
+        let exit_pos = Vector2d::new(walls.width() as i64 - 2, 1);
+        walls
+            .map(|pos, wall| match pos {
+                _ if pos == start_pos => 'S',
+                _ if pos == exit_pos => 'E',
+                _ if *wall => '#',
+                _ => '.',
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const SECOND_EXAMPLE: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input), Answer::from(7036));
+    }
+
+    #[test]
+    fn part1_second_example() {
+        let input = Day16::parse(SECOND_EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input), Answer::from(11048));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input), Answer::from(45));
+    }
+
+    #[test]
+    fn part2_second_example() {
+        let input = Day16::parse(SECOND_EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input), Answer::from(64));
+    }
+
+    #[test]
+    fn unreachable_exit() {
+        let input = Day16::parse("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(Day16::part1(&input), Answer::Unsolved);
+        assert_eq!(Day16::part2(&input), Answer::Unsolved);
+    }
+}
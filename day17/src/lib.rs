@@ -0,0 +1,392 @@
+//! Day 17: Chronospatial Computer, a 3-bit computer and the program outputting itself
+
+#[cfg(feature = "serde")]
+use aoc_core::Dump;
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+
+/// Values of the 3 registers
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Register {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Instruction {
+    pub fn from_opcode(opcode: usize) -> Option<Instruction> {
+        match opcode {
+            0 => Some(Instruction::Adv),
+            1 => Some(Instruction::Bxl),
+            2 => Some(Instruction::Bst),
+            3 => Some(Instruction::Jnz),
+            4 => Some(Instruction::Bxc),
+            5 => Some(Instruction::Out),
+            6 => Some(Instruction::Bdv),
+            7 => Some(Instruction::Cdv),
+            _ => None,
+        }
+    }
+}
+
+type Code = Vec<(Instruction, usize)>;
+
+// Programs may never halt: they are stopped after this number of instructions
+const MAX_STEPS: usize = 100000;
+// Register A candidates are searched below the divider of an iteration
+const MAX_DIVIDER_BITS: usize = 10;
+
+/// Initial registers and code of a program
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Program {
+    register: Register,
+    code_values: Vec<usize>,
+    code: Vec<(Instruction, usize)>,
+}
+
+impl Program {
+    pub fn register(&self) -> &Register {
+        &self.register
+    }
+
+    /// Opcodes and operands, as in the input
+    pub fn code_values(&self) -> &[usize] {
+        &self.code_values
+    }
+
+    /// Instructions with their operand
+    pub fn code(&self) -> &[(Instruction, usize)] {
+        &self.code
+    }
+
+    fn get_combo_operand_value(&self, value: usize) -> usize {
+        match value {
+            0..=3 => value,
+            4 => self.register.a,
+            5 => self.register.b,
+            6 => self.register.c,
+            _ => panic!("Unknown combo operand {value}"),
+        }
+    }
+
+    // None if the program does not halt
+    fn execute_internal(&mut self) -> Option<Vec<usize>> {
+        let mut pointer = 0_usize;
+        let mut output: Vec<usize> = vec![];
+
+        for _ in 0..MAX_STEPS {
+            if pointer >= self.code.len() {
+                return Some(output);
+            }
+
+            let (instr, operand) = &self.code[pointer];
+            match instr {
+                Instruction::Adv => {
+                    let value = self.get_combo_operand_value(*operand);
+                    self.register.a = shift_right(self.register.a, value);
+                    pointer += 1;
+                }
+                Instruction::Bxl => {
+                    self.register.b ^= *operand;
+                    pointer += 1;
+                }
+                Instruction::Bst => {
+                    let value = self.get_combo_operand_value(*operand);
+                    self.register.b = value % 8;
+                    pointer += 1;
+                }
+                Instruction::Jnz => match self.register.a {
+                    0 => pointer += 1,
+                    _ => pointer = *operand / 2,
+                },
+                Instruction::Bxc => {
+                    self.register.b ^= self.register.c;
+                    pointer += 1;
+                }
+                Instruction::Out => {
+                    let value = self.get_combo_operand_value(*operand);
+                    let out = value % 8;
+                    output.push(out);
+                    pointer += 1;
+                }
+                Instruction::Bdv => {
+                    let value = self.get_combo_operand_value(*operand);
+                    self.register.b = shift_right(self.register.a, value);
+                    pointer += 1;
+                }
+                Instruction::Cdv => {
+                    let value = self.get_combo_operand_value(*operand);
+                    self.register.c = shift_right(self.register.a, value);
+                    pointer += 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Runs the program until it halts, the output values are separated by commas (None if
+    /// the program does not halt)
+    pub fn execute(&mut self) -> Option<String> {
+        let output = self.execute_internal()?;
+        let str_output: Vec<String> = output.iter().map(|value| value.to_string()).collect();
+        Some(str_output.join(","))
+    }
+
+    /// Output of the program for another value of register A (None if it does not halt)
+    pub fn execute_with_a(&self, register_a: usize) -> Option<Vec<usize>> {
+        let mut program = self.clone();
+        program.register.a = register_a;
+        program.execute_internal()
+    }
+
+    /// Lowest positive value of register A making the program output its own code, for
+    /// programs shifting A by a constant at each iteration until it is null
+    pub fn find_a_value_to_match_code(initial_program: &Program) -> Option<usize> {
+        // First sum register A dividers for a single program iteration
+        let div_a: usize = initial_program
+            .code
+            .iter()
+            .filter_map(|(instr, value)| match (instr, value) {
+                (Instruction::Adv, 0..=3) => Some(Some(*value)),
+                (Instruction::Adv, _) => Some(None), // Divided by a register, can't be solved
+                _ => None,
+            })
+            .sum::<Option<usize>>()?;
+        if div_a > MAX_DIVIDER_BITS {
+            return None; // Too many candidates for each output value
+        }
+
+        let divider = 2_usize.pow(div_a as u32);
+        tracing::debug!(divider, "register A divider of an iteration");
+        if divider == 1 {
+            return None; // A never changes, the program can't halt after outputting its code
+        }
+
+        // For each program iteration, register A is divided by divider, and the program
+        // halts when A=0. So the last output only depends on A < divider, the previous one
+        // on A < divider^2 (whose quotient gives the last output)... Register A is then
+        // built from the last output to the first one
+        let last_iteration_index = initial_program.code_values.len() - 1;
+        Self::find_a_value_rec(initial_program, divider, 0, last_iteration_index)
+    }
+
+    // Smallest A value giving the code values from the given index, A / divider being
+    // the value found for the next index
+    fn find_a_value_rec(
+        initial_program: &Program,
+        divider: usize,
+        register_a: usize,
+        index: usize,
+    ) -> Option<usize> {
+        for ax in 0..divider {
+            let candidate = register_a.checked_mul(divider)?.checked_add(ax)?;
+            let Some(output) = initial_program.execute_with_a(candidate) else {
+                continue;
+            };
+            if output != initial_program.code_values[index..] {
+                continue;
+            }
+
+            tracing::debug!(index, ax, a = candidate, ?output, "output matches the code");
+
+            let found = match index {
+                0 if candidate > 0 => Some(candidate),
+                0 => None,
+                _ => Self::find_a_value_rec(initial_program, divider, candidate, index - 1),
+            };
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        None
+    }
+}
+
+// Division of a register by a power of 2
+fn shift_right(value: usize, shift: usize) -> usize {
+    u32::try_from(shift)
+        .ok()
+        .and_then(|shift| value.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+fn parse_register(registers: &mut Parser, name: char) -> Result<usize, ParseError> {
+    registers.literal(&format!("Register {name}: "))?;
+    let value = registers.unsigned()?;
+    registers.skip_whitespace();
+
+    Ok(value)
+}
+
+// Opcode and operand, combo operands can't be 7
+fn parse_instruction(values: &mut Parser) -> Result<[usize; 2], ParseError> {
+    let opcode_start = *values;
+    let opcode = values.unsigned()?;
+    let Some(instruction) = Instruction::from_opcode(opcode) else {
+        return Err(opcode_start.error("an opcode between 0 and 7"));
+    };
+    values.literal(",")?;
+
+    let operand_start = *values;
+    let operand = values.unsigned()?;
+    let max_operand = match instruction {
+        Instruction::Bxl | Instruction::Jnz | Instruction::Bxc => 7,
+        _ => 6,
+    };
+    if operand > max_operand {
+        return Err(operand_start.error(format!("an operand between 0 and {max_operand}")));
+    }
+
+    Ok([opcode, operand])
+}
+
+fn parse_input(raw_data: &str) -> Result<Program, ParseError> {
+    let [mut registers, mut program] = Parser::new(raw_data).sections_exact("a program")?;
+
+    let register = Register {
+        a: parse_register(&mut registers, 'A')?,
+        b: parse_register(&mut registers, 'B')?,
+        c: parse_register(&mut registers, 'C')?,
+    };
+    registers.end()?;
+
+    program.literal("Program: ")?;
+    let code_values: Vec<usize> = program.separated(",", parse_instruction)?.concat();
+    program.end()?;
+
+    let code: Code = code_values
+        .chunks(2)
+        .map(|pair| (Instruction::from_opcode(pair[0]).unwrap(), pair[1]))
+        .collect();
+
+    Ok(Program {
+        register,
+        code_values,
+        code,
+    })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    type Input = Program;
+
+    fn parse(raw_data: &str) -> Result<Program, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(program: &Program) -> Answer {
+        program.clone().execute().into()
+    }
+
+    fn part2(program: &Program) -> Answer {
+        Program::find_a_value_to_match_code(program).into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Dump for Day17 {
+    fn model(program: &Program) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(program)
+    }
+}
+
+impl Generate for Day17 {
+    // Output values
+    const DEFAULT_SIZE: usize = 16;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // Programs loop on register A, outputting a value computed from its lowest bits
+        // before shifting it, until it is null: B = A % 8, then mixing instructions on
+        // B and C (C being shifted from A by B), then A is shifted and B is output
+        let shift = match difficulty {
+            Difficulty::Hard => rng.between(1, 3) as usize,
+            _ => 3,
+        };
+        let mut mixing: Vec<[usize; 2]> = vec![[1, rng.below(8)]];
+        if difficulty != Difficulty::Easy {
+            mixing.push([7, 5]);
+            mixing.push([1, rng.below(8)]);
+            mixing.push([4, rng.below(8)]);
+        }
+        if difficulty == Difficulty::Hard {
+            mixing.push([1, rng.below(8)]);
+            // C must be shifted before being mixed into B, which can then be any value
+            rng.shuffle(&mut mixing[2..]);
+        }
+
+        let mut code = vec![[2, 4]];
+        code.extend(mixing);
+        code.extend([[0, shift], [5, 5], [3, 0]]);
+
+        // Enough bits in register A for the requested output values
+        let bits = (size.max(1) * shift).min(62);
+        let register_a = rng.between(1 << (bits - shift.min(bits)), (1 << bits) - 1);
+
+        let code: Vec<String> = code.concat().iter().map(usize::to_string).collect();
+        format!(
+            "Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            code.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const QUINE_EXAMPLE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part1(&input), Answer::from("4,6,3,5,6,3,5,2,1,0"));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(QUINE_EXAMPLE).unwrap();
+        assert_eq!(Day17::part2(&input), Answer::from(117440));
+    }
+
+    #[test]
+    fn endless_program_is_unsolved() {
+        let input =
+            Day17::parse("Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,3,0\n")
+                .unwrap();
+        assert_eq!(Day17::part1(&input), Answer::Unsolved);
+    }
+}
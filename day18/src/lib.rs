@@ -0,0 +1,304 @@
+//! Day 18: RAM Run, shortest path through a memory space corrupted by falling bytes
+
+use aoc_core::{
+    generate, search, Answer, Difficulty, Direction, Generate, Param, Params, ParseError, Parser,
+    Rng, Solution, Vector2d,
+};
+use std::collections::HashSet;
+
+/// Memory space, from the top left corner to the bottom right one, and the positions of the
+/// bytes in falling order
+pub struct Grid {
+    falling_bytes: Vec<Vector2d>,
+    size: Vector2d,
+
+    start_pos: Vector2d,
+    exit_pos: Vector2d,
+}
+
+impl Grid {
+    pub fn size(&self) -> Vector2d {
+        self.size
+    }
+
+    pub fn falling_bytes(&self) -> &[Vector2d] {
+        &self.falling_bytes
+    }
+
+    fn is_out_of_boundaries(&self, pos: &Vector2d) -> bool {
+        pos.x < 0 || pos.x >= self.size.x || pos.y < 0 || pos.y >= self.size.y
+    }
+
+    /// First byte cutting the exit off, the bytes before the given count being known not to
+    pub fn find_first_blocking_position(&self, n_start_fallen_bytes: usize) -> Option<Vector2d> {
+        let mut n_bytes = n_start_fallen_bytes;
+        loop {
+            n_bytes += 1;
+            if n_bytes > self.falling_bytes.len() {
+                break None;
+            }
+
+            if self.compute_lowest_score(n_bytes).is_none() {
+                break Some(self.falling_bytes[n_bytes - 1]);
+            }
+        }
+    }
+
+    /// Steps to the exit once the given count of bytes has fallen, if it can still be reached
+    pub fn compute_lowest_score(&self, n_fallen_bytes: usize) -> Option<usize> {
+        let corrupted_cells: HashSet<&Vector2d> =
+            HashSet::from_iter(self.falling_bytes.iter().take(n_fallen_bytes).clone());
+
+        let search_result = search::bfs(self.start_pos, |pos| {
+            Direction::CARDINALS
+                .into_iter()
+                .map(|move_dir| *pos + move_dir)
+                .filter(|next_pos| {
+                    !self.is_out_of_boundaries(next_pos) && !corrupted_cells.contains(next_pos)
+                })
+                .collect::<Vec<Vector2d>>()
+        });
+
+        search_result.distance(&self.exit_pos)
+    }
+}
+
+/// Falling bytes of a memory space of the given size (the example is smaller)
+pub fn parse_input(raw_data: &str, size: &Vector2d) -> Result<Grid, ParseError> {
+    let falling_bytes = Parser::new(raw_data).parse_lines(|line| {
+        let x = line.unsigned()?;
+        line.literal(",")?;
+        let y = line.unsigned()?;
+
+        Ok(Vector2d { x, y })
+    })?;
+
+    Ok(Grid {
+        falling_bytes,
+        size: *size,
+        start_pos: Vector2d { x: 0, y: 0 },
+        exit_pos: Vector2d {
+            x: size.x - 1,
+            y: size.y - 1,
+        },
+    })
+}
+
+pub const GRID_SIZE: Vector2d = Vector2d::new(71, 71);
+
+/// Values of the puzzle parameters, the example uses a smaller memory space and fewer bytes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryParams {
+    pub size: Vector2d,
+    pub fallen_bytes: usize,
+    pub first_checked_byte: usize,
+}
+
+impl MemoryParams {
+    fn new(params: &Params) -> MemoryParams {
+        MemoryParams {
+            size: Vector2d::new(params.get("width"), params.get("height")),
+            fallen_bytes: params.get("fallen_bytes"),
+            first_checked_byte: params.get("first_checked_byte"),
+        }
+    }
+}
+
+/// The memory space and the bytes falling into it
+pub struct MemorySpace {
+    pub grid: Grid,
+    pub params: MemoryParams,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "RAM Run";
+
+    // Size of the memory space, bytes fallen in part 1, and bytes known not to block the exit
+    // in part 2 (magic number to compute faster... depends on the input)
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", GRID_SIZE.x as u64, 1..=1000),
+        Param::new("height", GRID_SIZE.y as u64, 1..=1000),
+        Param::new("fallen_bytes", 1024, 0..=1000000),
+        Param::new("first_checked_byte", 2500, 0..=1000000),
+    ];
+
+    type Input = MemorySpace;
+
+    fn parse(raw_data: &str) -> Result<MemorySpace, ParseError> {
+        Self::parse_with(raw_data, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(raw_data: &str, params: &Params) -> Result<MemorySpace, ParseError> {
+        let params = MemoryParams::new(params);
+        Ok(MemorySpace {
+            grid: parse_input(raw_data, &params.size)?,
+            params,
+        })
+    }
+
+    fn part1(memory: &MemorySpace) -> Answer {
+        memory
+            .grid
+            .compute_lowest_score(memory.params.fallen_bytes)
+            .into()
+    }
+
+    fn part2(memory: &MemorySpace) -> Answer {
+        let blocking_pos = memory
+            .grid
+            .find_first_blocking_position(memory.params.first_checked_byte);
+        blocking_pos
+            .map(|pos| format!("{},{}", pos.x, pos.y))
+            .into()
+    }
+}
+
+impl Generate for Day18 {
+    // Falling bytes
+    const DEFAULT_SIZE: usize = 3450;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        Self::generate_with(rng, size, difficulty, &Params::defaults(Self::PARAMS))
+    }
+
+    fn generate_with(
+        rng: &mut Rng,
+        size: usize,
+        difficulty: Difficulty,
+        params: &Params,
+    ) -> String {
+        let memory_size = MemoryParams::new(params).size;
+
+        // The walls of a perfect maze fall first (in a random order), then some of its dead
+        // ends, so that the path to the exit is blocked later on harder inputs, and lastly the
+        // other corridors. The maze is cut from a bigger one, without its surrounding walls,
+        // to have corridors on the memory edges
+        let maze = generate::maze(
+            rng,
+            memory_size.x as usize + 2,
+            memory_size.y as usize + 2,
+            0.0,
+        );
+        let first_dead_ends_ratio = difficulty.pick(0.2, 0.35, 0.5);
+
+        let offset = Vector2d::new(1, 1);
+        let start_pos = Vector2d::new(0, 0);
+        let exit_pos = memory_size - Vector2d::new(1, 1);
+        let path: HashSet<Vector2d> = search::bfs(start_pos + offset, |pos| {
+            maze.neighbours4(pos)
+                .filter(|next_pos| !maze[*next_pos])
+                .collect::<Vec<_>>()
+        })
+        .path_to(&(exit_pos + offset))
+        .unwrap_or_default() // Corridors are connected, but the exit is a wall on even sides
+        .into_iter()
+        .map(|pos| pos - offset)
+        .collect();
+
+        let mut walls = vec![];
+        let mut dead_ends = vec![];
+        let mut path_corridors = vec![];
+        for (pos, wall) in maze.iter() {
+            let pos = pos - offset;
+            if pos.x < 0 || pos.y < 0 || pos.x >= memory_size.x || pos.y >= memory_size.y {
+                continue;
+            }
+
+            match wall {
+                true => walls.push(pos),
+                false if pos == start_pos || pos == exit_pos => {}
+                false if path.contains(&pos) => path_corridors.push(pos),
+                false => dead_ends.push(pos),
+            }
+        }
+        rng.shuffle(&mut walls);
+        rng.shuffle(&mut dead_ends);
+        let mut last_corridors =
+            dead_ends.split_off((dead_ends.len() as f64 * first_dead_ends_ratio) as usize);
+        last_corridors.extend(path_corridors);
+        rng.shuffle(&mut last_corridors);
+
+        walls
+            .into_iter()
+            .chain(dead_ends)
+            .chain(last_corridors)
+            .take(size)
+            .map(|pos| format!("{},{}\n", pos.x, pos.y))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn lowest_score_example() {
+        let grid = parse_input(EXAMPLE, &Vector2d::new(7, 7)).unwrap();
+        assert_eq!(grid.compute_lowest_score(12), Some(22));
+    }
+
+    #[test]
+    fn first_blocking_position_example() {
+        let grid = parse_input(EXAMPLE, &Vector2d::new(7, 7)).unwrap();
+        assert_eq!(
+            grid.find_first_blocking_position(12),
+            Some(Vector2d::new(6, 1))
+        );
+
+        // Until the last byte
+        let last_byte = EXAMPLE.find("6,1\n").unwrap() + "6,1\n".len();
+        let grid = parse_input(&EXAMPLE[..last_byte], &Vector2d::new(7, 7)).unwrap();
+        assert_eq!(
+            grid.find_first_blocking_position(12),
+            Some(Vector2d::new(6, 1))
+        );
+        let grid = parse_input(&EXAMPLE[..last_byte - 4], &Vector2d::new(7, 7)).unwrap();
+        assert_eq!(grid.find_first_blocking_position(12), None);
+    }
+
+    #[test]
+    fn generated_inputs_fit_the_memory_space() {
+        let config = aoc_core::Config::parse("[day18]\nwidth = 7\nheight = 7\n").unwrap();
+        let params = config.params(18, Day18::PARAMS).unwrap();
+        let raw_data = Day18::generate_with(&mut Rng::new(1), 100, Difficulty::Normal, &params);
+        let memory = Day18::parse_with(&raw_data, &params).unwrap();
+
+        let bytes: HashSet<Vector2d> = memory.grid.falling_bytes().iter().copied().collect();
+        assert_eq!(bytes.len(), memory.grid.falling_bytes().len());
+        assert!(bytes.iter().all(|pos| pos.x < 7 && pos.y < 7));
+        // Every free position falls, the start and the exit apart
+        assert_eq!(bytes.len(), 7 * 7 - 2);
+    }
+}
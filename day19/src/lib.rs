@@ -0,0 +1,191 @@
+//! Day 19: Linen Layout, towel designs made of the available stripe patterns
+
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// The available towel patterns
+pub struct TowelDesigner {
+    patterns: HashSet<String>,
+    max_pattern_len: usize,
+}
+
+impl TowelDesigner {
+    pub fn new(input_patterns: Vec<&str>) -> TowelDesigner {
+        let max_pattern_len = input_patterns
+            .iter()
+            .map(|str| str.len())
+            .max()
+            .unwrap_or(0);
+        let patterns = HashSet::from_iter(input_patterns.into_iter().map(|str| str.to_string()));
+        TowelDesigner {
+            patterns,
+            max_pattern_len,
+        }
+    }
+
+    pub fn patterns(&self) -> &HashSet<String> {
+        &self.patterns
+    }
+
+    /// Ways to arrange the patterns into the design (0 if it is impossible)
+    pub fn count_arrangements(&self, design: &str) -> usize {
+        self.is_possible(design, &mut HashMap::new())
+    }
+
+    fn is_possible(&self, design: &str, memoizer: &mut HashMap<String, usize>) -> usize {
+        if let Some(size) = memoizer.get(design) {
+            return *size;
+        }
+
+        let mut local_score = 0;
+        for chunk_size in 1..=design.len().min(self.max_pattern_len) {
+            let chunk = &design[0..chunk_size];
+
+            if self.patterns.contains(chunk) {
+                let score = match chunk_size == design.len() {
+                    true => 1, // Leaf
+                    false => self.is_possible(&design[chunk_size..], memoizer),
+                };
+
+                // The count grows exponentially with the length of the design: it saturates
+                // on degenerate inputs instead of overflowing
+                local_score = usize::saturating_add(local_score, score);
+            }
+        }
+
+        memoizer.insert(design.to_string(), local_score);
+
+        local_score
+    }
+
+    /// Possible designs, and the sum of their arrangements
+    pub fn count_possible_designs(&self, designs: &[String]) -> (usize, usize) {
+        let mut memoizer: HashMap<String, usize> = HashMap::new();
+        let mut valid_design_count = 0;
+        let mut valid_ways_count = 0;
+        for design in designs {
+            let score = self.is_possible(design, &mut memoizer);
+            tracing::trace!(design, ways = score, "design");
+            valid_ways_count = usize::saturating_add(valid_ways_count, score);
+            if score > 0 {
+                valid_design_count += 1;
+            }
+        }
+
+        (valid_design_count, valid_ways_count)
+    }
+}
+
+// Stripes colors: white, blue, black, red or green
+fn parse_stripes<'a>(parser: &mut Parser<'a>) -> Result<&'a str, ParseError> {
+    let stripes = parser.take_while(|c| "wubrg".contains(c));
+    match stripes.is_empty() {
+        true => Err(parser.error("stripe colors (`w`, `u`, `b`, `r` or `g`)")),
+        false => Ok(stripes),
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<(TowelDesigner, Vec<String>), ParseError> {
+    let [mut available, designs] = Parser::new(raw_data).sections_exact("towel designs")?;
+
+    let patterns = available.separated(", ", parse_stripes)?;
+    available.end()?;
+    let designs = designs.parse_lines(|line| parse_stripes(line).map(str::to_string))?;
+
+    Ok((TowelDesigner::new(patterns), designs))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Linen Layout";
+
+    type Input = (TowelDesigner, Vec<String>);
+
+    fn parse(raw_data: &str) -> Result<(TowelDesigner, Vec<String>), ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1((towel_designer, designs): &(TowelDesigner, Vec<String>)) -> Answer {
+        let (count, _) = towel_designer.count_possible_designs(designs);
+        count.into()
+    }
+
+    fn part2((towel_designer, designs): &(TowelDesigner, Vec<String>)) -> Answer {
+        let (_, ways_count) = towel_designer.count_possible_designs(designs);
+        ways_count.into()
+    }
+}
+
+impl Generate for Day19 {
+    // Designs
+    const DEFAULT_SIZE: usize = 400;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+        // One color has no single stripe towel, some designs can't be made because of it
+        let missing_color = *rng.pick(&COLORS);
+        let patterns_count = difficulty.pick(20, 150, 450);
+        let (length_min, length_max) = difficulty.pick((5, 20), (20, 60), (40, 60));
+
+        let mut patterns: Vec<String> = vec![];
+        while patterns.len() < patterns_count {
+            let pattern: String = (0..rng.between(1, 8)).map(|_| *rng.pick(&COLORS)).collect();
+            if pattern != missing_color.to_string() && !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+
+        let designs: Vec<String> = (0..size)
+            .map(|_| {
+                let length = rng.between(length_min, length_max) as usize;
+                let mut design = String::new();
+                while design.len() < length {
+                    design.push_str(rng.pick(&patterns[..]).as_str());
+                }
+
+                // A single missing stripe is usually enough to make the design impossible
+                if rng.chance(0.3) {
+                    let index = rng.below(design.len());
+                    design.replace_range(index..=index, &missing_color.to_string());
+                }
+
+                design
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part1(&input), Answer::from(6));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part2(&input), Answer::from(16));
+    }
+}
@@ -0,0 +1,199 @@
+//! Day 2: Red-Nosed Reports, safety of the reactor level reports
+
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+
+enum LevelsOrdering {
+    None,
+    Increasing,
+    Decreasing,
+    Unsafe,
+}
+
+/// Levels of a report, in reading order
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl Report {
+    pub fn new(levels: Vec<i32>) -> Self {
+        Report { levels }
+    }
+
+    pub fn levels(&self) -> &[i32] {
+        &self.levels
+    }
+
+    /// Levels are all increasing or all decreasing, by 1 to 3 at each step
+    pub fn is_safe(&self) -> bool {
+        Report::are_levels_safe(&self.levels)
+    }
+
+    fn get_ordering(level_diff: i64) -> LevelsOrdering {
+        match level_diff {
+            1..=3 => LevelsOrdering::Increasing,
+            -3..=-1 => LevelsOrdering::Decreasing,
+            _ => LevelsOrdering::Unsafe,
+        }
+    }
+
+    fn validate(previous: LevelsOrdering, current: LevelsOrdering) -> LevelsOrdering {
+        if let LevelsOrdering::None = previous {
+            return current;
+        }
+
+        match (previous, current) {
+            (LevelsOrdering::Decreasing, LevelsOrdering::Decreasing) => LevelsOrdering::Decreasing,
+            (LevelsOrdering::Increasing, LevelsOrdering::Increasing) => LevelsOrdering::Increasing,
+            _ => LevelsOrdering::Unsafe,
+        }
+    }
+
+    /// Safe, or safe once a single level is removed (Problem Dampener)
+    pub fn is_safe_with_dampener(&self) -> bool {
+        // With all levels
+        if Report::are_levels_safe(&self.levels) {
+            return true;
+        }
+
+        // Try all combinations by removing a single level each time
+        for index in 0..self.levels.len() {
+            let reduced_levels: Vec<i32> = self
+                .levels
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != index)
+                .map(|(_, level)| *level)
+                .collect();
+
+            if Report::are_levels_safe(&reduced_levels) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn are_levels_safe(levels: &Vec<i32>) -> bool {
+        let mut previous_ordering = LevelsOrdering::None;
+        let mut previous_level: Option<&i32> = None;
+
+        for level in levels {
+            if let Some(previous_level) = previous_level {
+                // Widened, as the difference of two levels may not fit in an i32
+                let diff = i64::from(*level) - i64::from(*previous_level);
+
+                // Compute report type with previous level
+                let current_ordering = Report::get_ordering(diff);
+                if let LevelsOrdering::Unsafe = current_ordering {
+                    return false;
+                }
+
+                // Check consistency
+                previous_ordering = Report::validate(previous_ordering, current_ordering);
+            }
+
+            if let LevelsOrdering::Unsafe = previous_ordering {
+                return false;
+            }
+
+            previous_level = Some(level);
+        }
+
+        true
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Report>;
+
+    fn parse(raw_data: &str) -> Result<Vec<Report>, ParseError> {
+        let separator = " ";
+
+        Parser::new(raw_data).parse_lines(|line| {
+            let levels = line.separated(separator, Parser::signed)?;
+            Ok(Report::new(levels))
+        })
+    }
+
+    fn part1(reports: &Vec<Report>) -> Answer {
+        reports
+            .iter()
+            .filter(|report| report.is_safe())
+            .count()
+            .into()
+    }
+
+    fn part2(reports: &Vec<Report>) -> Answer {
+        reports
+            .iter()
+            .filter(|report| report.is_safe_with_dampener())
+            .count()
+            .into()
+    }
+}
+
+impl Generate for Day2 {
+    // Reports
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let levels_max = difficulty.pick(6, 8, 20);
+        let bad_step_probability = difficulty.pick(0.02, 0.05, 0.15);
+
+        (0..size)
+            .map(|_| {
+                let direction = *rng.pick(&[-1, 1]);
+                // Levels stay positive, whatever the steps
+                let mut level = rng.between(4 * levels_max, 99);
+                let mut levels = vec![];
+                for _ in 0..rng.between(5, levels_max) {
+                    levels.push(level.to_string());
+                    level += match rng.chance(bad_step_probability) {
+                        true => rng.between(-4, 4),
+                        false => direction * rng.between(1, 3),
+                    };
+                }
+
+                levels.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), Answer::from(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), Answer::from(4));
+    }
+
+    #[test]
+    fn extreme_levels() {
+        let input = Day2::parse("2147483647 -2147483648\n-2147483648 2147483647 1 2\n").unwrap();
+        assert_eq!(Day2::part1(&input), Answer::from(0));
+        assert_eq!(Day2::part2(&input), Answer::from(1));
+    }
+}
@@ -0,0 +1,319 @@
+//! Day 20: Race Condition, cheats going through the walls of a racetrack
+
+use aoc_core::{
+    generate, search, Answer, Canvas, Difficulty, Direction, Generate, Grid, Param, Params,
+    ParseError, Rgb, Rng, Solution, Vector2d, Visualize,
+};
+use std::io;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Cell {
+    Empty,
+    Wall,
+}
+
+/// Map of the track
+#[derive(Clone)]
+pub struct Racetrack {
+    cells: Grid<Cell>,
+}
+
+impl Racetrack {
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    fn is_empty_cell(&self, pos: &Vector2d) -> bool {
+        let Some(cell) = self.cells.get(pos) else {
+            return false;
+        };
+
+        *cell == Cell::Empty
+    }
+
+    fn get_all_free_spaces_around(&self, pos: &Vector2d, dist_max: i64) -> Vec<Vector2d> {
+        let mut result = vec![];
+
+        for x in pos.x - dist_max..=(pos.x + dist_max) {
+            for y in pos.y - dist_max..=pos.y + dist_max {
+                let next_pos = Vector2d { x, y };
+                let dist = pos.distance(&next_pos);
+                if !self.cells.contains(&next_pos)
+                    || dist <= 1
+                    || dist > dist_max as usize
+                    || !self.is_empty_cell(&next_pos)
+                {
+                    continue;
+                }
+
+                result.push(next_pos);
+            }
+        }
+
+        result
+    }
+
+    /// Calls on_cheat with the start and end positions of every cheat (going through walls for
+    /// up to cheat_duration picoseconds) saving at least time_saved_min picoseconds
+    pub fn for_each_cheat<F>(
+        &self,
+        start_pos: &Vector2d,
+        exit_pos: &Vector2d,
+        cheat_duration: usize,
+        time_saved_min: usize,
+        mut on_cheat: F,
+    ) where
+        F: FnMut(&Vector2d, &Vector2d),
+    {
+        // Time to reach the exit, for every reachable position
+        let times_to_exit = search::bfs(*exit_pos, |pos| self.next_positions(pos));
+
+        // Get the best path for the initial grid
+        if let Some(mut ordered_path) = times_to_exit.path_to(start_pos) {
+            ordered_path.reverse();
+            let initial_time = ordered_path.len() - 1;
+
+            // For all positions on the initial path, try to reach empty positions where dist<=cheat_time
+            let dist_max = cheat_duration as i64;
+            for (cheat_start_time, cheat_start_pos) in ordered_path.iter().enumerate() {
+                let free_spaces = self.get_all_free_spaces_around(cheat_start_pos, dist_max);
+
+                for cheat_end_pos in free_spaces {
+                    if let Some(cheat_end_pos_time) = times_to_exit.distance(&cheat_end_pos) {
+                        let cheat_distance = cheat_end_pos.distance(cheat_start_pos);
+                        let total_time = cheat_start_time + cheat_distance + cheat_end_pos_time;
+                        if total_time < initial_time {
+                            let time_offset = initial_time - total_time;
+                            if time_offset >= time_saved_min {
+                                on_cheat(cheat_start_pos, &cheat_end_pos);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Count of the cheats saving at least time_saved_min picoseconds
+    pub fn get_cheats_count(
+        &self,
+        start_pos: &Vector2d,
+        exit_pos: &Vector2d,
+        cheat_duration: usize,
+        time_saved_min: usize,
+    ) -> usize {
+        let mut cheat_counter = 0;
+        self.for_each_cheat(
+            start_pos,
+            exit_pos,
+            cheat_duration,
+            time_saved_min,
+            |_, _| cheat_counter += 1,
+        );
+
+        cheat_counter
+    }
+
+    fn next_positions(&self, pos: &Vector2d) -> Vec<Vector2d> {
+        Direction::CARDINALS
+            .into_iter()
+            .map(|move_dir| *pos + move_dir)
+            .filter(|next_pos| self.is_empty_cell(next_pos))
+            .collect()
+    }
+}
+
+/// Values of the puzzle parameters, in picoseconds
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CheatParams {
+    pub part1_cheat: usize,
+    pub part2_cheat: usize,
+    pub min_time_saved: usize,
+}
+
+impl CheatParams {
+    fn new(params: &Params) -> CheatParams {
+        CheatParams {
+            part1_cheat: params.get("part1_cheat"),
+            part2_cheat: params.get("part2_cheat"),
+            min_time_saved: params.get("min_time_saved"),
+        }
+    }
+}
+
+/// The racetrack, from its start tile to its end tile
+pub struct Race {
+    pub racetrack: Racetrack,
+    pub start_pos: Vector2d,
+    pub exit_pos: Vector2d,
+    pub params: CheatParams,
+}
+
+impl Race {
+    fn cheats_count(&self, max_cheat_duration: usize) -> usize {
+        self.racetrack.get_cheats_count(
+            &self.start_pos,
+            &self.exit_pos,
+            max_cheat_duration,
+            self.params.min_time_saved,
+        )
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<(Racetrack, Vector2d, Vector2d), ParseError> {
+    let (cells, markers) = Grid::try_parse_with_markers(
+        raw_data,
+        &['S', 'E'],
+        |c| match c {
+            '.' | 'S' | 'E' => Some(Cell::Empty),
+            '#' => Some(Cell::Wall),
+            _ => None,
+        },
+        "`.`, `#`, `S` or `E`",
+    )?;
+
+    let Some(&start_pos) = markers.get(&'S') else {
+        return Err(ParseError::at_end(raw_data, "a start tile `S`"));
+    };
+    let Some(&exit_pos) = markers.get(&'E') else {
+        return Err(ParseError::at_end(raw_data, "an end tile `E`"));
+    };
+
+    Ok((Racetrack { cells }, start_pos, exit_pos))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Race Condition";
+
+    // Longest cheat of each part (in picoseconds), and time the counted cheats save at least
+    const PARAMS: &'static [Param] = &[
+        Param::new("part1_cheat", 2, 1..=100),
+        Param::new("part2_cheat", 20, 1..=100),
+        Param::new("min_time_saved", 100, 0..=1000000000),
+    ];
+
+    type Input = Race;
+
+    fn parse(raw_data: &str) -> Result<Race, ParseError> {
+        Self::parse_with(raw_data, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(raw_data: &str, params: &Params) -> Result<Race, ParseError> {
+        let (racetrack, start_pos, exit_pos) = parse_input(raw_data)?;
+        Ok(Race {
+            racetrack,
+            start_pos,
+            exit_pos,
+            params: CheatParams::new(params),
+        })
+    }
+
+    fn part1(race: &Race) -> Answer {
+        race.cheats_count(race.params.part1_cheat).into()
+    }
+
+    fn part2(race: &Race) -> Answer {
+        race.cheats_count(race.params.part2_cheat).into()
+    }
+}
+
+impl Visualize for Day20 {
+    // Track positions coloured by the count of (part 1) cheats starting from them
+    fn draw(race: &Race, canvas: &mut dyn Canvas) -> io::Result<()> {
+        let racetrack = &race.racetrack;
+        let mut cheat_starts = racetrack.cells.map(|_, _| 0_usize);
+        racetrack.for_each_cheat(
+            &race.start_pos,
+            &race.exit_pos,
+            race.params.part1_cheat,
+            race.params.min_time_saved,
+            |cheat_start, _| cheat_starts[*cheat_start] += 1,
+        );
+        let max_cheats = cheat_starts
+            .iter()
+            .map(|(_, count)| *count)
+            .max()
+            .unwrap_or(0);
+
+        let mut image = racetrack.cells.map(|pos, cell| match cell {
+            Cell::Wall => Rgb::GREY,
+            Cell::Empty if cheat_starts[pos] == 0 => Rgb::BLACK,
+            Cell::Empty => {
+                Rgb::BLUE.mix(&Rgb::YELLOW, cheat_starts[pos] as f64 / max_cheats as f64)
+            }
+        });
+        image.set(&race.start_pos, Rgb::GREEN);
+        image.set(&race.exit_pos, Rgb::RED);
+
+        canvas.still(&image)
+    }
+}
+
+impl Generate for Day20 {
+    // Side of the racetrack
+    const DEFAULT_SIZE: usize = 141;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // Without loops there is a single track between the start and the end
+        let loops = difficulty.pick(0.0, 0.02, 0.1);
+        let walls = generate::maze(rng, size, size, loops);
+
+        // Start in the top left corner, end in the bottom right one
+        let start_pos = Vector2d::new(1, 1);
+        let exit_pos = Vector2d::new(walls.width() as i64 - 2, walls.height() as i64 - 2);
+        walls
+            .map(|pos, wall| match pos {
+                _ if pos == start_pos => 'S',
+                _ if pos == exit_pos => 'E',
+                _ if *wall => '#',
+                _ => '.',
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    fn cheats_count(cheat_duration: usize, time_saved_min: usize) -> usize {
+        let (racetrack, start_pos, exit_pos) = parse_input(EXAMPLE).unwrap();
+        racetrack.get_cheats_count(&start_pos, &exit_pos, cheat_duration, time_saved_min)
+    }
+
+    #[test]
+    fn short_cheats_example() {
+        assert_eq!(cheats_count(2, 64), 1);
+        assert_eq!(cheats_count(2, 40), 2);
+        assert_eq!(cheats_count(2, 20), 5);
+        assert_eq!(cheats_count(2, 2), 44);
+    }
+
+    #[test]
+    fn long_cheats_example() {
+        assert_eq!(cheats_count(20, 76), 3);
+        assert_eq!(cheats_count(20, 74), 7);
+        assert_eq!(cheats_count(20, 50), 285);
+    }
+}
@@ -0,0 +1,370 @@
+//! Day 21: Keypad Conundrum, robots typing door codes on keypads through other robots
+
+mod utils;
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use utils::*;
+
+pub use utils::Keypad;
+
+#[derive(Clone, Copy)]
+pub enum NumericCommand {
+    A,
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+}
+
+impl NumericCommand {
+    fn get_position(&self) -> Vector2d {
+        match self {
+            NumericCommand::A => Vector2d { x: 2, y: 3 },
+            NumericCommand::Zero => Vector2d { x: 1, y: 3 },
+            NumericCommand::One => Vector2d { x: 0, y: 2 },
+            NumericCommand::Two => Vector2d { x: 1, y: 2 },
+            NumericCommand::Three => Vector2d { x: 2, y: 2 },
+            NumericCommand::Four => Vector2d { x: 0, y: 1 },
+            NumericCommand::Five => Vector2d { x: 1, y: 1 },
+            NumericCommand::Six => Vector2d { x: 2, y: 1 },
+            NumericCommand::Seven => Vector2d { x: 0, y: 0 },
+            NumericCommand::Eight => Vector2d { x: 1, y: 0 },
+            NumericCommand::Nine => Vector2d { x: 2, y: 0 },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DirectionalCommand {
+    A,
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+impl DirectionalCommand {
+    fn get_position(&self) -> Vector2d {
+        match self {
+            DirectionalCommand::A => Vector2d { x: 2, y: 0 },
+            DirectionalCommand::Up => Vector2d { x: 1, y: 0 },
+            DirectionalCommand::Left => Vector2d { x: 0, y: 1 },
+            DirectionalCommand::Down => Vector2d { x: 1, y: 1 },
+            DirectionalCommand::Right => Vector2d { x: 2, y: 1 },
+        }
+    }
+
+    fn get_command_from_move(dir: &Vector2d) -> DirectionalCommand {
+        match Direction::from_vector(dir) {
+            Some(Direction::Up) => DirectionalCommand::Up,
+            Some(Direction::Down) => DirectionalCommand::Down,
+            Some(Direction::Left) => DirectionalCommand::Left,
+            Some(Direction::Right) => DirectionalCommand::Right,
+            _ => panic!("Unknown directional command move"),
+        }
+    }
+
+    fn get_direction(&self) -> Option<Direction> {
+        match self {
+            DirectionalCommand::A => None,
+            DirectionalCommand::Up => Some(Direction::Up),
+            DirectionalCommand::Left => Some(Direction::Left),
+            DirectionalCommand::Down => Some(Direction::Down),
+            DirectionalCommand::Right => Some(Direction::Right),
+        }
+    }
+}
+
+impl fmt::Display for DirectionalCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get_direction() {
+            Some(direction) => write!(f, "{}", direction.to_char()),
+            None => write!(f, "A"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum KeypadCommand {
+    Numeric(NumericCommand),
+    Directional(DirectionalCommand),
+}
+
+impl KeypadCommand {
+    fn get_position(&self) -> Vector2d {
+        match self {
+            KeypadCommand::Directional(command) => command.get_position(),
+            KeypadCommand::Numeric(command) => command.get_position(),
+        }
+    }
+}
+
+/// Robot arm in front of a keypad, pointing at a key
+pub struct Robot {
+    keypad: Keypad,
+    arm_command: KeypadCommand,
+}
+
+impl Robot {
+    /// Robot typing on the door keypad, pointing at `A`
+    pub fn new_numeric() -> Self {
+        Robot {
+            keypad: Keypad::new([
+                ['7', '8', '9'],
+                ['4', '5', '6'],
+                ['1', '2', '3'],
+                ['#', '0', 'A'],
+            ]),
+            arm_command: KeypadCommand::Numeric(NumericCommand::A),
+        }
+    }
+
+    /// Robot typing on a directional keypad, pointing at `A`
+    pub fn new_directional() -> Self {
+        Robot {
+            keypad: Keypad::new([['#', '^', 'A'], ['<', 'v', '>']]),
+            arm_command: KeypadCommand::Directional(DirectionalCommand::A),
+        }
+    }
+
+    fn get_path_commands(start_pos: &Vector2d, path: &[Vector2d]) -> Vec<DirectionalCommand> {
+        let mut pos = start_pos;
+        path.iter()
+            .skip(1)
+            .map(|next_pos| {
+                let dir = *next_pos - *pos;
+                pos = next_pos;
+                DirectionalCommand::get_command_from_move(&dir)
+            })
+            .collect()
+    }
+
+    // Commands moving the arm from a key to another one, then pressing it
+    fn press_commands(&self, start_pos: &Vector2d, end_pos: &Vector2d) -> Vec<DirectionalCommand> {
+        let mut commands = vec![];
+        if start_pos != end_pos {
+            if let Some(best_path) = self.keypad.compute_best_path(start_pos, end_pos) {
+                commands = Self::get_path_commands(start_pos, &best_path);
+            }
+        }
+
+        commands.push(DirectionalCommand::A);
+        commands
+    }
+
+    /// Commands to type on the directional keypad controlling this robot, for the robot to press
+    /// the given keys
+    pub fn execute_sequence(
+        &mut self,
+        input_commands: Vec<KeypadCommand>,
+    ) -> Vec<DirectionalCommand> {
+        let mut output_commands = vec![];
+
+        // Move the arm
+        let mut arm_pos = self.arm_command.get_position();
+        for command in &input_commands {
+            let end_pos = command.get_position();
+            output_commands.extend(self.press_commands(&arm_pos, &end_pos));
+
+            arm_pos = end_pos;
+        }
+
+        self.arm_command = *input_commands.last().unwrap();
+
+        output_commands
+    }
+
+    /// Length of the sequence to type for a door code, through two robots on directional keypads
+    pub fn execute(input_commands: Vec<KeypadCommand>) -> usize {
+        let mut robot1 = Robot::new_numeric();
+        let output1 = robot1.execute_sequence(input_commands);
+
+        let mut robot2 = Robot::new_directional();
+        let output2 = robot2.execute_sequence(
+            output1
+                .iter()
+                .map(|c| KeypadCommand::Directional(*c))
+                .collect(),
+        );
+
+        let mut robot3 = Robot::new_directional();
+        let output3 = robot3.execute_sequence(
+            output2
+                .iter()
+                .map(|c| KeypadCommand::Directional(*c))
+                .collect(),
+        );
+
+        output3.len()
+    }
+
+    /// Same as execute, through the given count of robots on directional keypads: the sequences
+    /// grow exponentially with the robots, only the counts of consecutive commands are kept
+    /// (each press of a robot starts from `A`, where the arm of the next robot is back)
+    pub fn sequence_length(input_commands: Vec<KeypadCommand>, directional_robots: usize) -> usize {
+        let mut numeric_robot = Robot::new_numeric();
+        let commands = numeric_robot.execute_sequence(input_commands);
+
+        let directional_robot = Robot::new_directional();
+        let mut press_commands = HashMap::new();
+        let mut pair_counts = HashMap::new();
+        add_pairs(&commands, 1, &mut pair_counts);
+        for _ in 0..directional_robots {
+            let mut next_pair_counts = HashMap::new();
+            for ((from, to), count) in pair_counts {
+                let commands = press_commands.entry((from, to)).or_insert_with(|| {
+                    directional_robot.press_commands(&from.get_position(), &to.get_position())
+                });
+                add_pairs(commands, count, &mut next_pair_counts);
+            }
+            pair_counts = next_pair_counts;
+        }
+
+        pair_counts
+            .values()
+            .fold(0, |length, count| length.saturating_add(*count))
+    }
+}
+
+type CommandPair = (DirectionalCommand, DirectionalCommand);
+
+// Consecutive commands of a sequence typed count times, starting from `A`
+fn add_pairs(
+    commands: &[DirectionalCommand],
+    count: usize,
+    pairs: &mut HashMap<CommandPair, usize>,
+) {
+    let previous_commands = std::iter::once(&DirectionalCommand::A).chain(commands);
+    for (from, to) in previous_commands.zip(commands) {
+        let pair_count = pairs.entry((*from, *to)).or_default();
+        *pair_count = pair_count.saturating_add(count);
+    }
+}
+
+// Sum of the complexities of the codes, unsolved if it overflows
+fn complexity(all_commands: &[(Vec<KeypadCommand>, usize)], directional_robots: usize) -> Answer {
+    all_commands
+        .iter()
+        .try_fold(0_usize, |complexity, (commands, number)| {
+            Robot::sequence_length(commands.clone(), directional_robots)
+                .checked_mul(*number)
+                .and_then(|code_complexity| complexity.checked_add(code_complexity))
+        })
+        .into()
+}
+
+fn parse_input(raw_data: &str) -> Result<Vec<(Vec<KeypadCommand>, usize)>, ParseError> {
+    Parser::new(raw_data).parse_lines(|line| {
+        // Door codes are digits followed by `A`
+        let code = line.rest();
+        let number = line.unsigned()?;
+        line.literal("A")?;
+
+        let commands = code
+            .chars()
+            .map(|c| match c {
+                '0' => KeypadCommand::Numeric(NumericCommand::Zero),
+                '1' => KeypadCommand::Numeric(NumericCommand::One),
+                '2' => KeypadCommand::Numeric(NumericCommand::Two),
+                '3' => KeypadCommand::Numeric(NumericCommand::Three),
+                '4' => KeypadCommand::Numeric(NumericCommand::Four),
+                '5' => KeypadCommand::Numeric(NumericCommand::Five),
+                '6' => KeypadCommand::Numeric(NumericCommand::Six),
+                '7' => KeypadCommand::Numeric(NumericCommand::Seven),
+                '8' => KeypadCommand::Numeric(NumericCommand::Eight),
+                '9' => KeypadCommand::Numeric(NumericCommand::Nine),
+                _ => KeypadCommand::Numeric(NumericCommand::A),
+            })
+            .collect();
+
+        Ok((commands, number))
+    })
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Keypad Conundrum";
+
+    type Input = Vec<(Vec<KeypadCommand>, usize)>;
+
+    fn parse(raw_data: &str) -> Result<Vec<(Vec<KeypadCommand>, usize)>, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(all_commands: &Vec<(Vec<KeypadCommand>, usize)>) -> Answer {
+        complexity(all_commands, 2)
+    }
+
+    fn part2(all_commands: &Vec<(Vec<KeypadCommand>, usize)>) -> Answer {
+        complexity(all_commands, 25)
+    }
+}
+
+impl Generate for Day21 {
+    // Door codes
+    const DEFAULT_SIZE: usize = 5;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // Longer codes need more moves on the keypads
+        let digits_count = difficulty.pick(1, 3, 6);
+
+        (0..size)
+            .map(|_| {
+                let digits: String = (0..digits_count)
+                    .map(|_| char::from(b'0' + rng.below(10) as u8))
+                    .collect();
+                format!("{digits}A\n")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input), Answer::from(126384));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part2(&input), Answer::from(154115708116294_u64));
+    }
+
+    #[test]
+    fn counted_sequences_have_the_length_of_the_typed_ones() {
+        for (commands, _) in Day21::parse(EXAMPLE).unwrap() {
+            assert_eq!(
+                Robot::sequence_length(commands.clone(), 2),
+                Robot::execute(commands)
+            );
+        }
+    }
+
+    #[test]
+    fn overflowing_complexity_is_unsolved() {
+        let input = Day21::parse("12345678901234567890A\n").unwrap();
+        assert_eq!(Day21::part1(&input), Answer::Unsolved);
+    }
+}
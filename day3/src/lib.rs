@@ -0,0 +1,196 @@
+//! Day 3: Mull It Over, multiplications hidden in corrupted memory
+
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Rng, Solution};
+use regex::Regex;
+
+const DO_INSTRUCTION: &str = "do()";
+const DONT_INSTRUCTION: &str = "don't()";
+// Operands have 1 to 3 digits
+const MUL_PATTERN: &str = r"mul\(([0-9]{1,3}),([0-9]{1,3})\)";
+
+struct Accumulator<'a> {
+    enabled_segments: Vec<&'a str>,
+    enabled: bool,
+    start_index: usize,
+}
+
+impl<'a> Accumulator<'a> {
+    fn new() -> Self {
+        Accumulator {
+            enabled_segments: vec![],
+            enabled: true,
+            start_index: 0,
+        }
+    }
+}
+
+fn get_segments_enabled(raw_data: &str) -> Vec<&str> {
+    let keys = [DO_INSTRUCTION, DONT_INSTRUCTION];
+
+    // Get matching indexes
+    let mut matching_keys: Vec<(usize, &str)> = keys
+        .iter()
+        .flat_map(|key| raw_data.match_indices(key))
+        .collect();
+
+    matching_keys.sort_by_key(|(index, _)| *index);
+
+    // Keep only enabled segments
+    let mut acc = Accumulator::new();
+    for (index, instr) in matching_keys {
+        match (acc.enabled, instr) {
+            (false, DO_INSTRUCTION) => {
+                acc.enabled = true;
+                acc.start_index = index + DO_INSTRUCTION.len();
+            }
+            (true, DONT_INSTRUCTION) => {
+                let substr = &raw_data[acc.start_index..index];
+                acc.enabled_segments.push(substr);
+                acc.enabled = false;
+            }
+            _ => {}
+        };
+    }
+
+    // Manage end of the data
+    if acc.enabled {
+        let substr = &raw_data[acc.start_index..];
+        acc.enabled_segments.push(substr);
+    }
+
+    acc.enabled_segments
+}
+
+fn get_mul_result(raw_data: &str, re: &Regex) -> usize {
+    re.captures_iter(raw_data)
+        .map(|c| c.extract())
+        .map(|(_, [lvalue, rvalue])| {
+            (
+                lvalue.parse::<usize>().unwrap(),
+                rvalue.parse::<usize>().unwrap(),
+            )
+        })
+        .map(|(lvalue, rvalue)| lvalue * rvalue)
+        .sum()
+}
+
+/// Sum of the results of the valid `mul(X,Y)` instructions
+pub fn sum_of_products(memory: &str) -> usize {
+    let re = Regex::new(MUL_PATTERN).unwrap();
+    get_mul_result(memory, &re)
+}
+
+/// Same as sum_of_products, without the instructions disabled by `don't()` (until the next `do()`)
+pub fn sum_of_enabled_products(memory: &str) -> usize {
+    let re = Regex::new(MUL_PATTERN).unwrap();
+    get_segments_enabled(memory)
+        .iter()
+        .map(|segment| get_mul_result(segment, &re))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    // Corrupted memory is used as is
+    type Input = String;
+
+    fn parse(raw_data: &str) -> Result<String, ParseError> {
+        Ok(raw_data.to_string())
+    }
+
+    fn part1(memory: &String) -> Answer {
+        sum_of_products(memory).into()
+    }
+
+    fn part2(memory: &String) -> Answer {
+        sum_of_enabled_products(memory).into()
+    }
+}
+
+impl Generate for Day3 {
+    // Characters of the corrupted memory
+    const DEFAULT_SIZE: usize = 18000;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        const LINE_LENGTH: usize = 3000;
+        const NOISE: &[&str] = &[
+            "!", "@", "#", "$", "%", "^", "&", "*", "(", ")", "[", "]", "{", "}", "<", ">", ",",
+            ";", ":", "'", "?", "+", "-", "_", " ", "how", "who", "what", "when", "where", "why",
+            "select", "from",
+        ];
+        // Almost valid instructions
+        const DECOYS: &[&str] = &[
+            "mul[3,7]",
+            "mul(32,64]",
+            "mul ( 2 , 4 )",
+            "mul(4*",
+            "mul(6,9!",
+            "?(12,34)",
+            "do_not_",
+            "don't",
+            "do(",
+            "mul(,3)",
+            "mul(1 ,2)",
+            "mul()",
+        ];
+        let decoy_probability = difficulty.pick(0.02, 0.1, 0.3);
+        let switch_probability = difficulty.pick(0.0, 0.05, 0.15);
+
+        let mut memory = String::new();
+        let mut line_length = 0;
+        while memory.len() < size.max(1) {
+            let token = if rng.chance(0.2) {
+                format!("mul({},{})", rng.between(1, 999), rng.between(1, 999))
+            } else if rng.chance(switch_probability) {
+                rng.pick(&["do()", "don't()"]).to_string()
+            } else if rng.chance(decoy_probability) {
+                rng.pick(DECOYS).to_string()
+            } else {
+                rng.pick(NOISE).to_string()
+            };
+
+            line_length += token.len();
+            memory.push_str(&token);
+            if line_length >= LINE_LENGTH {
+                memory.push('\n');
+                line_length = 0;
+            }
+        }
+
+        // The memory always ends with a new line
+        if !memory.ends_with('\n') {
+            memory.push('\n');
+        }
+
+        memory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PART1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const EXAMPLE_PART2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE_PART1).unwrap();
+        assert_eq!(Day3::part1(&input), Answer::from(161));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE_PART2).unwrap();
+        assert_eq!(Day3::part2(&input), Answer::from(48));
+    }
+}
@@ -0,0 +1,166 @@
+//! Day 4: Ceres Search, words hidden in a letter grid
+
+use aoc_core::{
+    Answer, Difficulty, Direction, Generate, Grid, ParseError, Rng, Solution, Vector2d,
+};
+
+/// The word search puzzle
+pub struct Matrix {
+    grid: Grid<char>,
+}
+
+impl Matrix {
+    /// One row of letters per line
+    pub fn build(raw_data: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(raw_data, Some, "a letter")?;
+
+        Ok(Matrix { grid })
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Occurrences of the word written twice in an X, on both diagonals of a square
+    pub fn count_x(&self, word: &str) -> usize {
+        // Move cursor through all possible zones
+        self.grid
+            .positions()
+            .filter(|pos| self.contains_x(*pos, word))
+            .count()
+    }
+
+    fn contains_x(&self, upper_left_corner: Vector2d, word: &str) -> bool {
+        let dimension = word.len() as i64;
+        let offset = dimension - 1;
+
+        // Build 4 corners + diag for the current zone
+        let Vector2d { x, y } = upper_left_corner;
+        let pos_diag_pairs = [
+            (Vector2d { x, y }, Direction::DownRight),
+            (Vector2d { x: x + offset, y }, Direction::DownLeft),
+            (Vector2d { x, y: y + offset }, Direction::UpRight),
+            (
+                Vector2d {
+                    x: x + offset,
+                    y: y + offset,
+                },
+                Direction::UpLeft,
+            ),
+        ];
+
+        // Check 4 corners
+        let match_count = pos_diag_pairs
+            .iter()
+            .filter(|(pos, dir)| self.check_direction(pos, *dir, word))
+            .count();
+
+        // 2 matching diagonals => Ok
+        match_count == 2
+    }
+
+    /// Occurrences of the word in the 8 directions
+    pub fn count(&self, word: &str) -> usize {
+        // Move cursor through all possible positions
+        self.grid
+            .positions()
+            .map(|pos| self.count_in_all_directions(&pos, word))
+            .sum()
+    }
+
+    fn count_in_all_directions(&self, start_pos: &Vector2d, word: &str) -> usize {
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| self.check_direction(start_pos, *direction, word))
+            .count()
+    }
+
+    fn check_direction(&self, start_pos: &Vector2d, direction: Direction, word: &str) -> bool {
+        self.grid
+            .ray(start_pos, direction)
+            .map(|(_, c)| *c)
+            .take(word.len())
+            .eq(word.chars())
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Matrix;
+
+    fn parse(raw_data: &str) -> Result<Matrix, ParseError> {
+        Matrix::build(raw_data)
+    }
+
+    fn part1(matrix: &Matrix) -> Answer {
+        matrix.count("XMAS").into()
+    }
+
+    fn part2(matrix: &Matrix) -> Answer {
+        matrix.count_x("MAS").into()
+    }
+}
+
+impl Generate for Day4 {
+    // Side of the letters grid
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let side = size.max(1);
+        let mut grid = Grid::new(side, side, 'X');
+        for pos in grid.positions().collect::<Vec<_>>() {
+            grid.set(&pos, *rng.pick(&['X', 'M', 'A', 'S']));
+        }
+
+        // Additional words, crossing each other more often in bigger quantities
+        let words_count = difficulty.pick(0, side, 4 * side);
+        for _ in 0..words_count {
+            let start = Vector2d::new(rng.below(side) as i64, rng.below(side) as i64);
+            let direction = *rng.pick(&Direction::ALL);
+            let positions: Vec<Vector2d> = grid
+                .ray(&start, direction)
+                .map(|(pos, _)| pos)
+                .take(4)
+                .collect();
+            for (pos, letter) in positions.iter().zip("XMAS".chars()) {
+                grid.set(pos, letter);
+            }
+        }
+
+        grid.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), Answer::from(18));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), Answer::from(9));
+    }
+}
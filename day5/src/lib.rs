@@ -0,0 +1,294 @@
+//! Day 5: Print Queue, page ordering rules of the safety manual updates
+
+#[cfg(feature = "serde")]
+use aoc_core::Dump;
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Pages which must be printed after a given page
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageRules {
+    after_pages: HashSet<usize>,
+}
+
+impl PageRules {
+    fn new(after_page: usize) -> Self {
+        PageRules {
+            after_pages: [after_page].into(),
+        }
+    }
+
+    pub fn after_pages(&self) -> &HashSet<usize> {
+        &self.after_pages
+    }
+
+    fn validate<'a, I>(&self, before_pages: I) -> bool
+    where
+        I: Iterator<Item = &'a usize>,
+    {
+        for before_page in before_pages {
+            if self.after_pages.contains(before_page) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Ordering rules, by page printed first
+pub type Rules = HashMap<usize, PageRules>;
+/// Page numbers, in printing order
+pub type Update = Vec<usize>;
+
+/// The ordering rules and the updates to print
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrintQueue {
+    rules: Rules,
+    updates: Vec<Update>,
+}
+
+impl PrintQueue {
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn updates(&self) -> &[Update] {
+        &self.updates
+    }
+
+    /// Every page is printed after the pages it must follow
+    pub fn is_ordered(&self, update: &Update) -> bool {
+        validate_update(&self.rules, update)
+    }
+
+    /// Pages of the update in an order following the rules
+    pub fn sort(&self, update: &Update) -> Update {
+        sort_invalid_update(update, &self.rules)
+    }
+
+    /// Sum of the middle pages of the updates already in the right order
+    pub fn sum_of_ordered_middle_pages(&self) -> usize {
+        sum_valid_updates(&self.updates, &self.rules)
+    }
+
+    /// Sum of the middle pages of the other updates, once sorted
+    pub fn sum_of_sorted_middle_pages(&self) -> usize {
+        sum_invalid_updates(&self.updates, &self.rules)
+    }
+}
+
+fn validate_update(rules: &Rules, update: &Update) -> bool {
+    for (page_index, page) in update.iter().enumerate() {
+        if let Some(page_rules) = rules.get(page) {
+            if !page_rules.validate(update.iter().take(page_index)) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn sum_valid_updates(updates: &Vec<Update>, rules: &Rules) -> usize {
+    let mut result = 0;
+
+    for update in updates {
+        if validate_update(rules, update) {
+            let middle_index = (update.len() - 1) / 2;
+            result += update[middle_index];
+        }
+    }
+
+    result
+}
+
+// Insertion sort: unlike the slice sorts, it does not panic when the rules are not a total order
+fn sort_invalid_update(update: &Update, rules: &Rules) -> Update {
+    let mut new_update: Update = Vec::with_capacity(update.len());
+    for page in update {
+        // Before the first page which must be printed after it
+        let index = new_update
+            .iter()
+            .position(|next_page| {
+                rules
+                    .get(page)
+                    .is_some_and(|page_rules| page_rules.after_pages.contains(next_page))
+            })
+            .unwrap_or(new_update.len());
+        new_update.insert(index, *page);
+    }
+
+    new_update
+}
+
+fn sum_invalid_updates(updates: &Vec<Update>, rules: &Rules) -> usize {
+    let mut result = 0;
+    for update in updates {
+        if !validate_update(rules, update) {
+            let ordered_update = sort_invalid_update(update, rules);
+            let middle_index = (update.len() - 1) / 2;
+            result += ordered_update[middle_index];
+        }
+    }
+
+    result
+}
+
+// Page numbers are small enough for their sums not to overflow
+fn parse_page(parser: &mut Parser) -> Result<usize, ParseError> {
+    Ok(parser.unsigned::<u32>()? as usize)
+}
+
+fn parse_rules(section: &Parser) -> Result<Rules, ParseError> {
+    let page_pairs = section.parse_lines(|line| {
+        let lpage = parse_page(line)?;
+        line.literal("|")?;
+        Ok((lpage, parse_page(line)?))
+    })?;
+
+    let mut rules: Rules = HashMap::new();
+    for (lpage, rpage) in page_pairs {
+        match rules.get_mut(&lpage) {
+            Some(page_rules) => {
+                page_rules.after_pages.insert(rpage);
+            }
+            None => {
+                let new_page_rules = PageRules::new(rpage);
+                rules.insert(lpage, new_page_rules);
+            }
+        }
+    }
+
+    Ok(rules)
+}
+
+fn parse_updates(section: &Parser) -> Result<Vec<Update>, ParseError> {
+    section.parse_lines(|line| line.separated(",", parse_page))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = PrintQueue;
+
+    fn parse(raw_data: &str) -> Result<PrintQueue, ParseError> {
+        let [rules, updates] = Parser::new(raw_data).sections_exact("page updates")?;
+
+        Ok(PrintQueue {
+            rules: parse_rules(&rules)?,
+            updates: parse_updates(&updates)?,
+        })
+    }
+
+    fn part1(queue: &PrintQueue) -> Answer {
+        queue.sum_of_ordered_middle_pages().into()
+    }
+
+    fn part2(queue: &PrintQueue) -> Answer {
+        queue.sum_of_sorted_middle_pages().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Dump for Day5 {
+    fn model(print_queue: &PrintQueue) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(print_queue)
+    }
+}
+
+impl Generate for Day5 {
+    // Page updates
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // Pages on a circle, every page is before the next half of the circle: there is a rule
+        // for every pair of pages, and rules are consistent within each half of the circle
+        let pages_count: usize = difficulty.pick(25, 49, 89);
+        let mut pages: Vec<usize> = (10..10 + pages_count).collect();
+        rng.shuffle(&mut pages);
+
+        let mut rules = vec![];
+        for (index, page) in pages.iter().enumerate() {
+            for offset in 1..=pages_count / 2 {
+                rules.push(format!("{page}|{}", pages[(index + offset) % pages_count]));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        // Updates are taken from a half circle, odd lengths to have a middle page
+        let length_max = difficulty.pick(7, 23, pages_count.div_ceil(2)) as i64;
+        let updates: Vec<String> = (0..size)
+            .map(|_| {
+                let length = rng.between(1, length_max / 2) as usize * 2 + 1;
+                let start = rng.below(pages_count);
+                let mut offsets: Vec<usize> = (0..pages_count.div_ceil(2)).collect();
+                rng.shuffle(&mut offsets);
+                offsets.truncate(length);
+                // About half of the updates are in the right order
+                if rng.chance(0.5) {
+                    offsets.sort();
+                }
+
+                let update: Vec<String> = offsets
+                    .into_iter()
+                    .map(|offset| pages[(start + offset) % pages_count].to_string())
+                    .collect();
+                update.join(",")
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), Answer::from(143));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), Answer::from(123));
+    }
+}
@@ -0,0 +1,301 @@
+//! Day 6: Guard Gallivant, walk of a guard in a lab and obstructions trapping it in a loop
+
+use aoc_core::{
+    render, Answer, Canvas, Difficulty, Direction, Generate, Grid, ParseError, Rgb, Rng, Solution,
+    Vector2d, Visualize,
+};
+use std::io;
+
+/// Position and facing direction of the guard
+#[derive(Clone)]
+pub struct Guard {
+    position: Vector2d,
+    direction: Direction,
+}
+
+impl Guard {
+    pub fn position(&self) -> Vector2d {
+        self.position
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+}
+
+impl PartialEq for Guard {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.direction == other.direction
+    }
+}
+
+/// Lab position, visited cells keep the guard state of the first visit
+#[derive(Clone)]
+pub enum Cell {
+    Empty,
+    Visited(Guard),
+    Obstacle,
+}
+
+fn parse_input(raw_data: &str) -> Result<(Grid<Cell>, Guard), ParseError> {
+    let guard_chars = Direction::CARDINALS.map(Direction::to_char);
+
+    let (mut grid, markers) = Grid::try_parse_with_markers(
+        raw_data,
+        &guard_chars,
+        |cell_char| match cell_char {
+            '.' => Some(Cell::Empty),
+            '#' => Some(Cell::Obstacle),
+            _ if guard_chars.contains(&cell_char) => Some(Cell::Empty),
+            _ => None,
+        },
+        "`.`, `#` or a guard (`^`, `>`, `v`, `<`)",
+    )?;
+
+    // Markers are each found once, but the guard has a single one of them
+    let mut guards: Vec<(char, Vector2d)> = markers.into_iter().collect();
+    guards.sort_by_key(|(_, position)| (position.y, position.x));
+    if let Some((guard_char, position)) = guards.get(1) {
+        return Err(ParseError::new(
+            position.y as usize,
+            position.x as usize,
+            "a single guard",
+            format!("`{guard_char}`"),
+        ));
+    }
+    let Some(&(direction_char, position)) = guards.first() else {
+        return Err(ParseError::at_end(raw_data, "a guard"));
+    };
+    let guard = Guard {
+        position,
+        direction: Direction::from_char(direction_char).unwrap(),
+    };
+    grid.set(&position, Cell::Visited(guard.clone()));
+
+    Ok((grid, guard))
+}
+
+enum MoveGuardResult {
+    Outside,
+    Loop,
+}
+
+// Moves the guard to the next cell (or turns it in front of an obstacle),
+// returns the end of the walk if any
+fn step_guard(grid: &mut Grid<Cell>, guard: &mut Guard) -> Option<MoveGuardResult> {
+    let next_pos = guard.position + guard.direction;
+
+    let next_cell = grid.get(&next_pos);
+    match next_cell {
+        None => return Some(MoveGuardResult::Outside),
+        Some(Cell::Empty) => {
+            guard.position = next_pos;
+            grid.set(&next_pos, Cell::Visited(guard.clone()));
+        }
+        Some(Cell::Visited(last_guard)) => {
+            guard.position = next_pos;
+            if last_guard == guard {
+                return Some(MoveGuardResult::Loop);
+            }
+        }
+        Some(Cell::Obstacle) => {
+            guard.direction = guard.direction.turn_right();
+        }
+    }
+
+    None
+}
+
+fn move_guard(grid: &mut Grid<Cell>, guard: &mut Guard) -> MoveGuardResult {
+    // Visited cells only keep the first direction of the guard, so some loops are not seen there:
+    // a walk longer than the number of positions and directions is a loop anyway
+    let max_steps = 4 * grid.width() * grid.height();
+    for _ in 0..max_steps {
+        if let Some(result) = step_guard(grid, guard) {
+            return result;
+        }
+    }
+
+    MoveGuardResult::Loop
+}
+
+/// Distinct positions visited by the guard before leaving the lab
+pub fn count_visited_cells(grid: &Grid<Cell>, guard: &Guard) -> usize {
+    let mut grid = grid.clone();
+    let mut guard = guard.clone();
+    move_guard(&mut grid, &mut guard);
+
+    grid.iter()
+        .filter(|(_, cell)| matches!(cell, Cell::Visited(_)))
+        .count()
+}
+
+/// Positions where a new obstacle traps the guard in a loop
+pub fn count_obstructions(grid: &Grid<Cell>, guard: &Guard) -> usize {
+    let mut counter = 0;
+
+    // Put obstacles on every possible position on the grid
+    for obstacle_pos in grid.positions() {
+        let mut next_grid = grid.clone();
+        let mut next_guard = guard.clone();
+
+        // Insert obstacle
+        match grid.get(&obstacle_pos) {
+            Some(Cell::Empty) => {
+                next_grid.set(&obstacle_pos, Cell::Obstacle);
+            }
+            _ => continue,
+        };
+
+        // Check result
+        if let MoveGuardResult::Loop = move_guard(&mut next_grid, &mut next_guard) {
+            counter += 1;
+        }
+    }
+
+    counter
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = (Grid<Cell>, Guard);
+
+    fn parse(raw_data: &str) -> Result<(Grid<Cell>, Guard), ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1((grid, guard): &(Grid<Cell>, Guard)) -> Answer {
+        count_visited_cells(grid, guard).into()
+    }
+
+    fn part2((grid, guard): &(Grid<Cell>, Guard)) -> Answer {
+        count_obstructions(grid, guard).into()
+    }
+}
+
+fn cell_color(cell: &Cell) -> Rgb {
+    match cell {
+        Cell::Empty => Rgb::BLACK,
+        Cell::Visited(_) => Rgb::BLUE,
+        Cell::Obstacle => Rgb::GREY,
+    }
+}
+
+fn draw_walk(grid: &Grid<Cell>, guard: &Guard) -> Grid<Rgb> {
+    let mut image = render::colorize(grid, &cell_color);
+    image.set(&guard.position, Rgb::RED);
+    image
+}
+
+impl Visualize for Day6 {
+    // The walk of the guard, until it leaves the area
+    fn draw((grid, guard): &(Grid<Cell>, Guard), canvas: &mut dyn Canvas) -> io::Result<()> {
+        let mut grid = grid.clone();
+        let mut guard = guard.clone();
+
+        canvas.frame(&draw_walk(&grid, &guard))?;
+        while step_guard(&mut grid, &mut guard).is_none() {
+            canvas.frame(&draw_walk(&grid, &guard))?;
+        }
+
+        canvas.still(&draw_walk(&grid, &guard))
+    }
+}
+
+impl Generate for Day6 {
+    // Side of the lab
+    const DEFAULT_SIZE: usize = 130;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let side = size.max(1);
+        let obstacle_probability = difficulty.pick(0.02, 0.05, 0.1);
+
+        let mut lab = Grid::new(side, side, '.');
+        for pos in lab.positions().collect::<Vec<_>>() {
+            if rng.chance(obstacle_probability) {
+                lab.set(&pos, '#');
+            }
+        }
+
+        let guard_pos = Vector2d::new(rng.below(side) as i64, rng.below(side) as i64);
+        lab.set(&guard_pos, rng.pick(&Direction::CARDINALS).to_char());
+
+        lab.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), Answer::from(41));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), Answer::from(6));
+    }
+
+    #[test]
+    fn single_guard() {
+        let err = Day6::parse("..^\n>.#\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Day6::parse("^.^\n..#\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert!(Day6::parse("...\n..#\n").is_err());
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        frames: usize,
+        stills: Vec<Grid<Rgb>>,
+    }
+
+    impl Canvas for Recorder {
+        fn still(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+            self.stills.push(image.clone());
+            Ok(())
+        }
+
+        fn frame(&mut self, _image: &Grid<Rgb>) -> io::Result<()> {
+            self.frames += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn draw_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        let mut recorder = Recorder::default();
+        Day6::draw(&input, &mut recorder).unwrap();
+
+        assert!(recorder.frames > 41);
+        assert_eq!(recorder.stills.len(), 1);
+        let walked = recorder.stills[0]
+            .iter()
+            .filter(|(_, color)| **color != Rgb::BLACK && **color != Rgb::GREY)
+            .count();
+        assert_eq!(walked, 41);
+    }
+}
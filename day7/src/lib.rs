@@ -0,0 +1,186 @@
+//! Day 7: Bridge Repair, operators missing from calibration equations
+
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Parser, Rng, Solution};
+
+// Left value whose concatenation with the right value gives the result, if any
+fn unconcat(result: usize, rvalue: usize) -> Option<usize> {
+    let digits = rvalue.checked_ilog10().unwrap_or(0) + 1;
+    match 10_usize.checked_pow(digits) {
+        Some(shift) => (result % shift == rvalue).then_some(result / shift),
+        // No result has more digits than the right value: the left value is 0
+        None => (result == rvalue).then_some(0),
+    }
+}
+
+/// Test value and the numbers to combine (at least one)
+pub struct Equation {
+    result: usize,
+    numbers: Vec<usize>,
+}
+
+impl Equation {
+    pub fn result(&self) -> usize {
+        self.result
+    }
+
+    pub fn numbers(&self) -> &[usize] {
+        &self.numbers
+    }
+
+    /// Some combination of `+` and `*` (and `||` if enabled), evaluated left to right, gives
+    /// the test value
+    pub fn check(&self, use_concat_operator: bool) -> bool {
+        // The last operator is undone from the test value, down to the first number
+        self.check_rec(self.numbers.len() - 1, self.result, use_concat_operator)
+    }
+
+    // Each operator is undone only when its inverse exists, which prunes most branches
+    fn check_rec(&self, index: usize, partial_result: usize, use_concat_operator: bool) -> bool {
+        let number = self.numbers[index];
+        if index == 0 {
+            return partial_result == number;
+        }
+
+        let check_previous = |previous_result: Option<usize>| {
+            previous_result.is_some_and(|previous_result| {
+                self.check_rec(index - 1, previous_result, use_concat_operator)
+            })
+        };
+
+        check_previous(partial_result.checked_sub(number))
+            // Any product by 0 is 0
+            || (number == 0 && partial_result == 0)
+            || (number != 0
+                && partial_result.is_multiple_of(number)
+                && check_previous(Some(partial_result / number)))
+            || (use_concat_operator && check_previous(unconcat(partial_result, number)))
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<Vec<Equation>, ParseError> {
+    Parser::new(raw_data).parse_lines(|line| {
+        let result = line.unsigned()?;
+        line.literal(": ")?;
+        let numbers = line.separated(" ", Parser::unsigned)?;
+
+        Ok(Equation { result, numbers })
+    })
+}
+
+// Sum of the results of the valid equations, unsolved if it overflows
+fn calibration_result(equations: &[Equation], use_concat_operator: bool) -> Answer {
+    equations
+        .iter()
+        .filter(|equation| equation.check(use_concat_operator))
+        .try_fold(0_usize, |sum, equation| sum.checked_add(equation.result))
+        .into()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<Equation>;
+
+    fn parse(raw_data: &str) -> Result<Vec<Equation>, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(equations: &Vec<Equation>) -> Answer {
+        calibration_result(equations, false)
+    }
+
+    fn part2(equations: &Vec<Equation>) -> Answer {
+        calibration_result(equations, true)
+    }
+}
+
+impl Generate for Day7 {
+    // Equations
+    const DEFAULT_SIZE: usize = 850;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        let numbers_max = difficulty.pick(4, 8, 12);
+        let wrong_probability = difficulty.pick(0.3, 0.5, 0.7);
+
+        let mut equations = String::new();
+        let mut equations_count = 0;
+        while equations_count < size {
+            let numbers: Vec<usize> = (0..rng.between(2, numbers_max))
+                .map(|_| rng.between(1, 999) as usize)
+                .collect();
+
+            // Operators are evaluated left to right, results as large as the real ones at most
+            let result =
+                numbers[1..]
+                    .iter()
+                    .try_fold(numbers[0], |result, &number| match rng.below(3) {
+                        0 => result.checked_add(number),
+                        1 => result.checked_mul(number),
+                        _ => result
+                            .checked_mul(10_usize.pow(number.ilog10() + 1))
+                            .and_then(|result| result.checked_add(number)),
+                    });
+            let Some(mut result) = result.filter(|result| *result < 1_000_000_000_000_000) else {
+                continue;
+            };
+            if rng.chance(wrong_probability) {
+                result = result.saturating_add(rng.between(1, 1000) as usize);
+            }
+
+            let numbers: Vec<String> = numbers.iter().map(usize::to_string).collect();
+            equations.push_str(&format!("{result}: {}\n", numbers.join(" ")));
+            equations_count += 1;
+        }
+
+        equations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), Answer::from(3749));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), Answer::from(11387));
+    }
+
+    #[test]
+    fn overflows() {
+        let max = usize::MAX;
+        let input = Day7::parse(&format!("{max}: {max}\n{max}: {max} 1\n")).unwrap();
+        assert_eq!(Day7::part1(&input), Answer::Unsolved);
+
+        // Branches past the test value are cut, whatever the count of numbers
+        let numbers = vec!["3"; 60].join(" ");
+        let input = Day7::parse(&format!("5: {numbers}\n")).unwrap();
+        assert_eq!(Day7::part2(&input), Answer::from(0));
+
+        // Including when a later number is 0
+        let numbers = vec!["3"; 40].join(" ");
+        let input = Day7::parse(&format!("5: {numbers} 0\n12: 3 0 4 3\n")).unwrap();
+        assert_eq!(Day7::part2(&input), Answer::from(12));
+    }
+}
@@ -0,0 +1,216 @@
+//! Day 8: Resonant Collinearity, antinodes created by pairs of antennas
+
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Rng, Solution};
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+
+type Vector2d = aoc_core::Vector2d<i32>;
+
+/// Letter or digit
+pub type Frequency = char;
+
+/// Size of the map and positions of the antennas, by frequency
+pub struct Grid {
+    size: Vector2d,
+    antennas_per_frequency: HashMap<Frequency, Vec<Vector2d>>,
+}
+
+impl Grid {
+    pub fn size(&self) -> Vector2d {
+        self.size
+    }
+
+    pub fn antennas(&self, frequency: Frequency) -> &[Vector2d] {
+        self.antennas_per_frequency
+            .get(&frequency)
+            .map_or(&[], |antennas| antennas)
+    }
+
+    fn out_of_boundaries(&self, pos: &Vector2d) -> bool {
+        pos.x < 0 || pos.x >= self.size.x || pos.y < 0 || pos.y >= self.size.y
+    }
+
+    fn get_antinode_pos(
+        &self,
+        a1: &Vector2d,
+        a2: &Vector2d,
+        use_harmonics: bool,
+    ) -> Option<HashSet<Vector2d>> {
+        let diff = *a2 - *a1;
+
+        if use_harmonics {
+            let mut result = HashSet::new();
+            let mut pos = *a1;
+            while !self.out_of_boundaries(&pos) {
+                result.insert(pos);
+                pos += diff;
+            }
+
+            return Some(result);
+        }
+
+        if 0 == diff.x && 0 == diff.y {
+            return None;
+        }
+
+        let pos = *a2 + diff;
+        match self.out_of_boundaries(&pos) {
+            true => None,
+            false => {
+                let mut result = HashSet::new();
+                result.insert(pos);
+                Some(result)
+            }
+        }
+    }
+
+    /// Distinct antinode positions on the map: at the same distance beyond each antenna of
+    /// a pair, or anywhere on their line with resonant harmonics
+    pub fn count_antinodes(&self, use_harmonics: bool) -> usize {
+        let mut result: HashSet<Vector2d> = HashSet::new();
+
+        for antenna_locations in self.antennas_per_frequency.values() {
+            for (index, antenna_pos) in antenna_locations.iter().enumerate() {
+                for (other_index, other_antenna_pos) in antenna_locations.iter().enumerate() {
+                    if other_index == index {
+                        continue;
+                    }
+
+                    if let Some(antinode_positions) =
+                        self.get_antinode_pos(antenna_pos, other_antenna_pos, use_harmonics)
+                    {
+                        for pos in antinode_positions {
+                            result.insert(pos);
+                        }
+                    }
+                }
+            }
+        }
+
+        result.len()
+    }
+}
+
+fn parse_input(raw_data: &str) -> Result<Grid, ParseError> {
+    let mut size_x = 0;
+    let mut size_y = 0;
+
+    let mut antennas_per_frequency: HashMap<Frequency, Vec<Vector2d>> = HashMap::new();
+    for (y, line) in raw_data.lines().enumerate() {
+        size_y = max(size_y, y);
+        for (x, frequency) in line.chars().enumerate() {
+            size_x = max(size_x, x);
+            let pos = Vector2d::new(x as i32, y as i32);
+            if frequency != '.' {
+                if !frequency.is_ascii_alphanumeric() {
+                    return Err(ParseError::new(
+                        y,
+                        x,
+                        "`.` or an antenna frequency (letter or digit)",
+                        format!("`{frequency}`"),
+                    ));
+                }
+
+                match antennas_per_frequency.get_mut(&frequency) {
+                    Some(locations) => {
+                        locations.push(pos);
+                    }
+                    None => {
+                        let locations = vec![pos];
+                        antennas_per_frequency.insert(frequency, locations);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Grid {
+        size: Vector2d {
+            x: size_x as i32 + 1,
+            y: size_y as i32 + 1,
+        },
+        antennas_per_frequency,
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = Grid;
+
+    fn parse(raw_data: &str) -> Result<Grid, ParseError> {
+        parse_input(raw_data)
+    }
+
+    fn part1(grid: &Grid) -> Answer {
+        grid.count_antinodes(false).into()
+    }
+
+    fn part2(grid: &Grid) -> Answer {
+        grid.count_antinodes(true).into()
+    }
+}
+
+impl Generate for Day8 {
+    // Side of the city map
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let side = size.max(1);
+        let frequencies_count = difficulty.pick(10, 40, FREQUENCIES.len());
+        let antennas_max = difficulty.pick(3, 4, 6);
+
+        let mut free_cells: Vec<usize> = (0..side * side).collect();
+        rng.shuffle(&mut free_cells);
+
+        let mut map = aoc_core::Grid::new(side, side, '.');
+        for frequency in FREQUENCIES.chars().take(frequencies_count) {
+            for _ in 0..rng.between(2, antennas_max) {
+                let Some(cell) = free_cells.pop() else {
+                    break;
+                };
+                let pos = aoc_core::Vector2d::new((cell % side) as i64, (cell / side) as i64);
+                map.set(&pos, frequency);
+            }
+        }
+
+        map.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input), Answer::from(14));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input), Answer::from(34));
+    }
+}
@@ -0,0 +1,316 @@
+//! Day 9: Disk Fragmenter, compaction of the files of a disk map
+
+#[cfg(feature = "serde")]
+use aoc_core::Dump;
+use aoc_core::{Answer, Difficulty, Generate, ParseError, Rng, Solution};
+use std::collections::{HashSet, VecDeque};
+
+/// Index of the file in the disk map
+pub type FileId = usize;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlockType {
+    Empty,
+    File(FileId),
+}
+
+/// Contiguous blocks of a file or of free space
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
+    pub size: usize,
+    pub block_type: BlockType,
+}
+
+/// Layout of the disk, files and free spaces alternating
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskMap {
+    blocks: VecDeque<Block>,
+    file_slots: usize,
+    empty_slots: usize,
+}
+
+impl DiskMap {
+    pub fn blocks(&self) -> &VecDeque<Block> {
+        &self.blocks
+    }
+
+    /// Same layout with a block for each unit of a file or free space
+    pub fn with_unit_blocks(&self) -> DiskMap {
+        let blocks = self
+            .blocks
+            .iter()
+            .flat_map(|block| {
+                (0..block.size).map(|_| Block {
+                    size: 1,
+                    block_type: block.block_type.clone(),
+                })
+            })
+            .collect();
+
+        DiskMap {
+            blocks,
+            file_slots: self.file_slots,
+            empty_slots: self.empty_slots,
+        }
+    }
+
+    /// Checksum once the file blocks are moved one at a time to the leftmost free block
+    /// (needs a disk map of unit blocks)
+    pub fn checksum(&self) -> usize {
+        // get first the files which will be moved...
+        let mut files_to_move: VecDeque<FileId> = VecDeque::from([]);
+        for (index, block) in self.blocks.iter().rev().enumerate() {
+            if index >= self.empty_slots {
+                break;
+            }
+
+            match block.block_type {
+                BlockType::Empty => {}
+                BlockType::File(file_id) => {
+                    files_to_move.push_back(file_id);
+                }
+            }
+        }
+
+        // ...then replace empty slots at the beginning
+        let mut compressed_blocks = vec![];
+        for (index, block) in self.blocks.iter().enumerate() {
+            if index >= self.blocks.len() - self.empty_slots {
+                break;
+            }
+
+            match block.block_type {
+                BlockType::Empty => {
+                    let file_id = files_to_move.pop_front().unwrap();
+                    compressed_blocks.push(file_id);
+                }
+                BlockType::File(file_id) => {
+                    compressed_blocks.push(file_id);
+                }
+            }
+        }
+
+        compressed_blocks
+            .iter()
+            .enumerate()
+            .map(|(index, file_id)| index * file_id)
+            .sum()
+    }
+
+    /// Checksum once whole files are moved to the leftmost free span large enough, in
+    /// decreasing file ID order
+    pub fn checksum_by_block(&mut self) -> usize {
+        self.move_files();
+
+        let mut checksum = 0_usize;
+        let mut block_index = 0;
+        for block in &self.blocks {
+            for _ in 0..block.size {
+                if let BlockType::File(id) = block.block_type {
+                    checksum += block_index * id;
+                }
+
+                block_index += 1;
+            }
+        }
+
+        checksum
+    }
+
+    fn move_files(&mut self) {
+        let mut processed_files = HashSet::new();
+        while processed_files.len() != self.file_slots {
+            self.move_last_file(&mut processed_files);
+        }
+    }
+
+    fn move_last_file(&mut self, processed_files: &mut HashSet<usize>) {
+        // Get the last file not already processed
+        let (file_index, file_block, file_id) = self
+            .blocks
+            .iter_mut()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, block)| match block.block_type {
+                BlockType::File(id) if !processed_files.contains(&id) => {
+                    Some((index, block.clone(), id))
+                }
+                _ => None,
+            })
+            .next()
+            .unwrap();
+
+        processed_files.insert(file_id);
+
+        // Get the index of the first matching empty block (if any)
+        let empty_data = self
+            .blocks
+            .iter()
+            .enumerate()
+            .find(|(_, block)| match block.block_type {
+                BlockType::Empty => block.size >= file_block.size,
+                _ => false,
+            });
+
+        if let Some((empty_index, empty_block)) = empty_data {
+            // Swap blocks if possible
+            if empty_index < file_index {
+                // Swap
+                if empty_block.size == file_block.size {
+                    self.blocks.swap(file_index, empty_index);
+                }
+                // Update
+                else {
+                    // Move empty slots at the end
+                    self.blocks[file_index] = Block {
+                        size: file_block.size,
+                        block_type: BlockType::Empty,
+                    };
+
+                    // Move file and update remaining empty slots
+                    self.blocks[empty_index].size -= file_block.size;
+                    self.blocks.insert(empty_index, file_block);
+                }
+            }
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = DiskMap;
+
+    // One block for each block size digit
+    fn parse(raw_data: &str) -> Result<DiskMap, ParseError> {
+        let mut file_slots = 0_usize;
+        let mut empty_slots = 0_usize;
+        let mut blocks = VecDeque::new();
+        for (index, value) in raw_data.trim_end().chars().enumerate() {
+            let Some(block_size) = value.to_digit(10) else {
+                return Err(ParseError::new(
+                    0,
+                    index,
+                    "a block size digit",
+                    format!("`{value}`"),
+                ));
+            };
+            let block_size = block_size as usize;
+
+            let id = index / 2;
+            let block_type = match index % 2 {
+                // file
+                0 => {
+                    file_slots += 1;
+                    BlockType::File(id)
+                }
+                // empty slot
+                _ => {
+                    empty_slots += block_size;
+                    BlockType::Empty
+                }
+            };
+
+            blocks.push_back(Block {
+                size: block_size,
+                block_type,
+            });
+        }
+
+        Ok(DiskMap {
+            blocks,
+            file_slots,
+            empty_slots,
+        })
+    }
+
+    fn part1(disk_map: &DiskMap) -> Answer {
+        disk_map.with_unit_blocks().checksum().into()
+    }
+
+    fn part2(disk_map: &DiskMap) -> Answer {
+        disk_map.clone().checksum_by_block().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Dump for Day9 {
+    // Block layouts of both parts
+    fn model(disk_map: &DiskMap) -> serde_json::Result<serde_json::Value> {
+        Ok(serde_json::json!({
+            "part1": serde_json::to_value(disk_map.with_unit_blocks())?,
+            "part2": serde_json::to_value(disk_map)?,
+        }))
+    }
+}
+
+impl Generate for Day9 {
+    // Length of the disk map
+    const DEFAULT_SIZE: usize = 19999;
+
+    fn generate(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+        // Files and free spaces alternate, starting and ending with a file
+        let length = size.max(1) / 2 * 2 + 1;
+        let block_size_max = difficulty.pick(3, 6, 9);
+
+        let mut disk_map: String = (0..length)
+            .map(|index| {
+                let block_size = match index % 2 {
+                    0 => rng.between(1, block_size_max),
+                    _ => rng.between(0, block_size_max),
+                };
+                char::from(b'0' + block_size as u8)
+            })
+            .collect();
+        disk_map.push('\n');
+
+        disk_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), Answer::from(1928));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), Answer::from(2858));
+    }
+
+    proptest! {
+        // With single block files, moving whole files is the same as moving blocks
+        #[test]
+        fn single_block_files_compact_the_same(
+            gaps in prop::collection::vec(0..=9_u8, 0..40),
+        ) {
+            let disk_map: String = gaps
+                .iter()
+                .map(|gap| format!("1{gap}"))
+                .chain(["1".to_string()])
+                .collect();
+
+            let disk_map = Day9::parse(&disk_map).unwrap();
+            let by_unit = disk_map.with_unit_blocks().checksum();
+            let by_block = disk_map.clone().checksum_by_block();
+            prop_assert_eq!(by_unit, by_block);
+        }
+    }
+}
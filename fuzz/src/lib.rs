@@ -0,0 +1,31 @@
+use aoc_core::{Config, Solution};
+
+// Any input must either be rejected by the parser or be solved, without panicking
+// (overflows included, fuzz targets are built with debug assertions).
+// The first bytes give the parameters of the day, one byte each: 255 is the default value,
+// other bytes are added to the lowest value (the examples use small values)
+pub fn parse_and_solve<S: Solution>(data: &[u8]) {
+    let Some((param_bytes, raw_data)) = data.split_at_checked(S::PARAMS.len()) else {
+        return;
+    };
+    let Ok(raw_data) = std::str::from_utf8(raw_data) else {
+        return;
+    };
+
+    let mut config = Config::default();
+    for (param, byte) in S::PARAMS.iter().zip(param_bytes) {
+        let value = match byte {
+            255 => param.default,
+            _ => (param.range.start() + u64::from(*byte)).min(*param.range.end()),
+        };
+        config
+            .set(&format!("day{}.{}={value}", S::DAY, param.name))
+            .unwrap();
+    }
+    let params = config.params(S::DAY, S::PARAMS).unwrap();
+
+    if let Ok(input) = S::parse_with(raw_data, &params) {
+        S::part1(&input);
+        S::part2(&input);
+    }
+}